@@ -1,26 +1,51 @@
 use clap::{Args, Parser, Subcommand};
-use rkubectl_args::{EnvFile, File, KeyValue};
+use rkubectl_args::{EnvFile, FieldSelector, File, KeyValue, LabelSelector};
 
 use super::*;
 
+pub use command::Advanced;
 pub use command::ApiResource;
 pub use command::ApiResources;
+pub use command::Apply;
+pub use command::Attach;
 pub use command::Auth;
+pub use command::Autoscale;
 pub use command::Basic;
 pub use command::ClusterInfo;
 pub use command::ClusterManagement;
 pub use command::Command;
 pub use command::Config;
 pub use command::ConfigMap;
+pub use command::Cp;
 pub use command::Create;
 pub use command::CreateResource;
 pub use command::Debug;
 pub use command::Delete;
+pub use command::Describe;
+pub use command::Diff;
 pub use command::Dump;
+pub use command::Edit;
+pub use command::Events;
+pub use command::Exec;
+pub use command::Explain;
 pub use command::Get;
+pub use command::Logs;
 pub use command::Node;
+pub use command::Patch;
+pub use command::PortForward;
+pub use command::Proxy;
+pub use command::Replace;
+pub use command::Rollout;
+pub use command::RolloutHistory;
+pub use command::RolloutPause;
+pub use command::RolloutRestart;
+pub use command::RolloutResume;
+pub use command::RolloutStatus;
+pub use command::RolloutUndo;
+pub use command::Scale;
 pub use command::Secret;
 pub use command::TroubleshootingDebugging;
+pub use command::Wait;
 
 use context::Context;
 
@@ -43,6 +68,9 @@ pub struct Cli {
     #[command(flatten, next_display_order = 3000)]
     pub config: KubeConfigOptions,
 
+    #[command(flatten, next_display_order = 4000)]
+    pub selector: SelectorOptions,
+
     #[arg(short, long, value_enum, global = true, display_order = 10000)]
     pub output: Option<OutputFormat>,
 
@@ -71,7 +99,11 @@ impl Cli {
         let namespace = self.namespace.namespace();
         let kubeapi = Kubeapi::new(&self.config, &self.options, self.debug)
             .await?
-            .with_namespace(namespace);
+            .with_namespace(namespace)
+            .with_selectors(
+                self.selector.selector.as_ref(),
+                self.selector.field_selector.as_ref(),
+            );
         info!(
             ctx = kubeapi.cluster_url(),
             namespace = ?kubeapi.namespace(),
@@ -112,6 +144,21 @@ impl NamespaceOptions {
     }
 }
 
+#[derive(Clone, Debug, Default, Args)]
+pub struct SelectorOptions {
+    /// Selector (label query) to filter on, supports '=', '==', '!=', 'in', 'notin'.
+    /// (e.g. -l key1=value1,key2=value2,key3 in (value3)).
+    /// Matching objects must satisfy all of the specified label constraints.
+    #[arg(short = 'l', long, global = true, value_parser = LabelSelector::value_parser())]
+    pub selector: Option<LabelSelector>,
+
+    /// Selector (field query) to filter on, supports '=', '==', and '!='.
+    /// (e.g. --field-selector key1=value1,key2=value2).
+    /// The server only supports a limited number of field queries per type.
+    #[arg(long, global = true, value_parser = FieldSelector::value_parser())]
+    pub field_selector: Option<FieldSelector>,
+}
+
 #[expect(dead_code)]
 fn metadata(name: impl ToString) -> metav1::ObjectMeta {
     let name = Some(name.to_string());
@@ -10,8 +10,15 @@ use k8s::SecretExt;
 
 use super::*;
 
+pub use advanced::Advanced;
+pub use advanced::Apply;
+pub use advanced::Diff;
+pub use advanced::Patch;
+pub use advanced::Replace;
+pub use advanced::Wait;
 pub use api_resource::ApiResource;
 pub use api_resource::ApiResources;
+pub use autoscale::Autoscale;
 pub use basic::Basic;
 pub use basic::Create;
 pub use basic::CreateResource;
@@ -21,21 +28,45 @@ pub use cluster::Dump;
 pub use config::Config;
 pub use configmap::ConfigMap;
 pub use delete::Delete;
+pub use edit::Edit;
+pub use explain::Explain;
 pub use get::Get;
 pub use node::Node;
+pub use rollout::Rollout;
+pub use rollout::RolloutHistory;
+pub use rollout::RolloutPause;
+pub use rollout::RolloutRestart;
+pub use rollout::RolloutResume;
+pub use rollout::RolloutStatus;
+pub use rollout::RolloutUndo;
+pub use scale::Scale;
 pub use secret::Secret;
+pub use troubleshoot::Attach;
 pub use troubleshoot::Auth;
+pub use troubleshoot::Cp;
 pub use troubleshoot::Debug;
+pub use troubleshoot::Describe;
+pub use troubleshoot::Events;
+pub use troubleshoot::Exec;
+pub use troubleshoot::Logs;
+pub use troubleshoot::PortForward;
+pub use troubleshoot::Proxy;
 pub use troubleshoot::TroubleshootingDebugging;
 
+mod advanced;
 mod api_resource;
+mod autoscale;
 mod basic;
 mod cluster;
 mod config;
 mod configmap;
 mod delete;
+mod edit;
+mod explain;
 mod get;
 mod node;
+mod rollout;
+mod scale;
 mod secret;
 mod troubleshoot;
 
@@ -58,6 +89,9 @@ pub enum Command {
     #[command(flatten)]
     TroubleshootingDebugging(TroubleshootingDebugging),
 
+    #[command(flatten)]
+    Advanced(Advanced),
+
     /// Print the supported API resources on the server.
     ApiResources(ApiResources),
 
@@ -99,6 +133,7 @@ impl Command {
             Self::Deploy(deploy) => deploy.exec(context).await,
             Self::ClusterManagement(cluster_management) => cluster_management.exec(context).await,
             Self::TroubleshootingDebugging(tsd) => tsd.exec(context).await,
+            Self::Advanced(advanced) => advanced.exec(context).await,
             Self::ApiResources(api_resources) => api_resources.exec(context).await,
             Self::ApiVersions => self.api_versions(context).await,
             Self::Config(config) => config.exec(context),
@@ -155,10 +190,10 @@ impl Command {
 // #[command(subcommand_help_heading = "Basic Commands (Intermediate)")]
 pub enum Intermediate {
     ///  Get documentation for a resource
-    Explain,
+    Explain(Explain),
     Get(Get),
     /// Edit a resource on the server
-    Edit,
+    Edit(Edit),
     /// Delete resources by file names, stdin, resources and names, or by resources and label selector
     Delete(Delete),
 }
@@ -166,9 +201,9 @@ pub enum Intermediate {
 impl Intermediate {
     async fn exec(self, context: &Context) -> RkResult<()> {
         match self {
-            Self::Explain => Err(RkError::todo()),
+            Self::Explain(explain) => explain.exec(context).await,
             Self::Get(get) => get.exec(context).await,
-            Self::Edit => Err(RkError::todo()),
+            Self::Edit(edit) => edit.exec(context).await,
             Self::Delete(delete) => delete.exec(context).await,
         }
     }
@@ -179,22 +214,22 @@ impl Intermediate {
 
 pub enum Deploy {
     /// Manage the rollout of a resource
-    Rollout,
+    #[command(subcommand)]
+    Rollout(Rollout),
 
     /// Set a new size for a deployment, replica set, or replication controller
-    Scale,
+    Scale(Scale),
 
     /// Auto-scale a deployment, replica set, stateful set, or replication controller
-    Autoscale,
+    Autoscale(Autoscale),
 }
 
 impl Deploy {
     async fn exec(self, context: &Context) -> RkResult<()> {
-        context.not_implemented(&self);
         match self {
-            Self::Rollout => Err(RkError::todo()),
-            Self::Scale => Err(RkError::todo()),
-            Self::Autoscale => Err(RkError::todo()),
+            Self::Rollout(rollout) => rollout.exec(context).await,
+            Self::Scale(scale) => scale.exec(context).await,
+            Self::Autoscale(autoscale) => autoscale.exec(context).await,
         }
     }
 }
@@ -0,0 +1,43 @@
+use super::*;
+
+pub use apply::Apply;
+pub use diff::Diff;
+pub use patch::Patch;
+pub use replace::Replace;
+pub use wait::Wait;
+
+mod apply;
+mod diff;
+mod patch;
+mod replace;
+mod wait;
+
+/// Advanced Commands
+#[derive(Clone, Debug, Subcommand)]
+pub enum Advanced {
+    /// Diff the live version against a would-be applied version
+    Diff(Diff),
+    /// Apply a configuration to a resource by file name or stdin
+    Apply(Apply),
+    /// Update fields of a resource
+    Patch(Patch),
+    /// Replace a resource by file name or stdin
+    Replace(Replace),
+    /// Experimental: Wait for a specific condition on one or many resources
+    Wait(Wait),
+    /// Build a kustomization target from a directory or URL
+    Kustomize,
+}
+
+impl Advanced {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        match self {
+            Self::Diff(diff) => diff.exec(context).await,
+            Self::Apply(apply) => apply.exec(context).await,
+            Self::Patch(patch) => patch.exec(context).await,
+            Self::Replace(replace) => replace.exec(context).await,
+            Self::Wait(wait) => wait.exec(context).await,
+            Self::Kustomize => Err(RkError::todo()),
+        }
+    }
+}
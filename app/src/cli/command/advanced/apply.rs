@@ -0,0 +1,207 @@
+use clap::ArgAction;
+use rkubectl_resource::LAST_APPLIED_CONFIG;
+use rkubectl_resource::ManifestObject;
+use rkubectl_resource::MergeKeys;
+use rkubectl_resource::PatchStrategy;
+use rkubectl_resource::apply_patch;
+use rkubectl_resource::patch_strategic;
+use rkubectl_resource::three_way_patch;
+
+use super::*;
+
+/// Apply a configuration to a resource by file name or stdin.
+///
+///  The resource name must be specified. This resource will be created if it doesn't exist yet.
+/// The configuration is applied server-side by default, with the server tracking which fields are
+/// owned by which field manager. With --server-side=false it is applied client-side instead.
+///
+///  JSON and YAML formats are accepted.
+///
+/// Examples:
+///   # Apply the configuration in pod.json to a pod
+///   kubectl apply -f ./pod.json
+///
+///   # Apply the JSON passed into stdin to a pod
+///   cat pod.json | kubectl apply -f -
+///
+///   # Apply all the manifests in a directory and its subdirectories
+///   kubectl apply -R -f ./manifests/
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Apply {
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// Name of the manager used to track field ownership.
+    /// Defaults to "kubectl", or "kubectl-client-side-apply" with --server-side=false.
+    #[arg(long)]
+    field_manager: Option<String>,
+
+    /// The files that contain the configurations to apply.
+    #[arg(short, long, required = true)]
+    filename: Vec<String>,
+
+    /// If true, server-side apply will force the changes against conflicts.
+    #[arg(long)]
+    force_conflicts: bool,
+
+    /// Process the directory used in -f, --filename recursively.
+    /// Useful when you want to manage related manifests organized within the same directory.
+    #[arg(long, short = 'R')]
+    recursive: bool,
+
+    /// If true, apply runs in the server instead of the client.
+    /// With --server-side=false the object is three-way merged with the configuration recorded
+    /// in its last-applied-configuration annotation.
+    #[arg(
+        long,
+        default_value_t = true,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        action = ArgAction::Set,
+    )]
+    server_side: bool,
+
+    #[command(flatten)]
+    params: ShowParams,
+}
+
+impl Apply {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let namespace = kubeapi.show_namespace();
+
+        let mut conflicts = vec![];
+        for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+            let object = ManifestObject::new(kubeapi, manifest).await?;
+            let api = object.api(kubeapi)?;
+            let result = if self.server_side {
+                self.server_side_apply(kubeapi, &api, &object).await
+            } else {
+                self.client_side_apply(kubeapi, &api, &object).await
+            };
+            let (applied, operation) = match result {
+                Ok(applied) => applied,
+                Err(kube::Error::Api(status)) if self.server_side && status.is_conflict() => {
+                    conflicts.push(conflict_report(&object, &status));
+                    continue;
+                }
+                Err(err) => return Err(object.error(err).into()),
+            };
+
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => {
+                    println!("{object} {operation}{}", self.dry_run.suffix());
+                }
+                output => println!("{}", applied.output(namespace, self.params, output)),
+            }
+        }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(RkError::ApplyConflict(conflicts.join("\n")))
+        }
+    }
+
+    async fn server_side_apply(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        object: &ManifestObject,
+    ) -> kube::Result<(api::DynamicObject, &'static str)> {
+        let operation = "serverside-applied";
+        if self.dry_run == DryRun::Client {
+            return Ok((object.object().clone(), operation));
+        }
+
+        let manager = self.field_manager.as_deref().unwrap_or("kubectl");
+        let pp = kubeapi.apply_params(manager, self.force_conflicts, self.dry_run);
+        let patch = api::Patch::Apply(object.object());
+        let applied = api.patch(&object.name(), &pp, &patch).await?;
+        Ok((applied, operation))
+    }
+
+    /// Classic `kubectl apply`, creating the object or patching it with the three-way merge
+    /// of the last applied configuration, the live object and the manifest
+    async fn client_side_apply(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        object: &ManifestObject,
+    ) -> kube::Result<(api::DynamicObject, &'static str)> {
+        let manager = self
+            .field_manager
+            .as_deref()
+            .unwrap_or("kubectl-client-side-apply");
+        let name = object.name();
+        let mut modified = object.object().clone();
+        rkubectl_resource::save_config(&mut modified).map_err(kube::Error::SerdeError)?;
+
+        let Some(current) = api.get_opt(&name).await? else {
+            if self.dry_run == DryRun::Client {
+                return Ok((modified, "created"));
+            }
+            let pp = kubeapi.post_params_with_dry_run(manager, self.dry_run);
+            let created = api.create(&pp, &modified).await?;
+            return Ok((created, "created"));
+        };
+
+        let original = rkubectl_resource::last_applied(&current);
+        if original.is_none() {
+            eprintln!(
+                "Warning: {object} is missing the {LAST_APPLIED_CONFIG} annotation which is required by apply --server-side=false. \
+                The missing annotation will be patched automatically."
+            );
+        }
+        let modified = json::to_value(&modified).map_err(kube::Error::SerdeError)?;
+        let live = json::to_value(&current).map_err(kube::Error::SerdeError)?;
+        let patch = |strategy| three_way_patch(original.as_ref(), &modified, &live, strategy);
+
+        let resource = object.resource();
+        let keys = MergeKeys::new(kubeapi, &resource.api_version, &resource.kind).await;
+        let strategic = patch(PatchStrategy::Strategic(&keys));
+        if strategic.as_object().is_some_and(json::Map::is_empty) {
+            return Ok((current, "unchanged"));
+        }
+        if self.dry_run == DryRun::Client {
+            let configured = apply_patch(&live, &strategic, PatchStrategy::Strategic(&keys));
+            let configured = json::from_value(configured).map_err(kube::Error::SerdeError)?;
+            return Ok((configured, "configured"));
+        }
+
+        let pp = kubeapi.patch_params(manager, self.dry_run);
+        let merge = || patch(PatchStrategy::Merge);
+        let configured = patch_strategic(api, &name, &pp, strategic, merge).await?;
+        Ok((configured, "configured"))
+    }
+}
+
+/// Same as kubectl, one line per field owned by another manager
+fn conflict_report(object: &ManifestObject, status: &kube::core::Status) -> String {
+    let causes = status
+        .details
+        .as_ref()
+        .map(|details| details.causes.as_slice())
+        .unwrap_or_default();
+    if causes.is_empty() {
+        return format!("Apply failed for {object}: {}", status.message);
+    }
+
+    let conflicts = if causes.len() == 1 {
+        "1 conflict".to_string()
+    } else {
+        format!("{} conflicts", causes.len())
+    };
+    iter::once(format!("Apply failed with {conflicts} for {object}:"))
+        .chain(
+            causes
+                .iter()
+                .map(|cause| format!("  - {}: {}", cause.field, cause.message)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,244 @@
+use std::io::IsTerminal;
+use std::process;
+
+use owo_colors::OwoColorize;
+use rkubectl_resource::ManifestObject;
+use similar::TextDiff;
+
+use super::*;
+
+/// Diff configurations specified by file name or stdin between the current online configuration,
+/// and the configuration as it would be if applied.
+///
+///  The would-be configuration is computed with a server-side dry-run apply. Both versions are
+/// compared as YAML, without managedFields, resourceVersion, generation and status.
+///
+///  KUBECTL_EXTERNAL_DIFF environment variable can be used to select your own diff command. It is
+/// run with the directories of the live and the merged objects as its last two arguments.
+///
+/// Exit status:
+///  0 No differences were found.
+///  1 Differences were found.
+///  >1 Kubectl or diff failed with an error.
+///
+/// Examples:
+///   # Diff resources included in pod.json
+///   kubectl diff -f pod.json
+///
+///   # Diff file read from stdin
+///   cat service.yaml | kubectl diff -f -
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Diff {
+    /// Program to compare the live and merged directories with, e.g. "diff -u -N" or "meld".
+    #[arg(long, env = "KUBECTL_EXTERNAL_DIFF", value_name = "PROGRAM")]
+    external: Option<String>,
+
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl")]
+    field_manager: String,
+
+    /// Filename, directory, or URL to files contains the configuration to diff
+    #[arg(short, long, required = true)]
+    filename: Vec<String>,
+
+    /// If true, server-side apply will force the changes against conflicts.
+    #[arg(long)]
+    force_conflicts: bool,
+
+    /// Process the directory used in -f, --filename recursively.
+    /// Useful when you want to manage related manifests organized within the same directory.
+    #[arg(long, short = 'R')]
+    recursive: bool,
+}
+
+/// Live and would-be versions of one object, as YAML
+#[derive(Debug)]
+struct Compared {
+    name: String,
+    live: String,
+    merged: String,
+}
+
+impl Diff {
+    const DIFFERENT: i32 = 1;
+    const FAILED: i32 = 2;
+
+    /// Reports failures here, exiting with a code above 1 so that they are not mistaken for differences
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        self.diff(context).await.map_err(|err| {
+            let code = exit_code(&err);
+            if !matches!(err, RkError::Exit(_)) {
+                eprintln!("{:?}", miette::Report::new(err));
+            }
+            RkError::Exit(code)
+        })
+    }
+
+    async fn diff(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let pp = kubeapi.apply_params(&self.field_manager, self.force_conflicts, DryRun::Server);
+
+        let mut compared = vec![];
+        for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+            let object = ManifestObject::new(kubeapi, manifest).await?;
+            let api = object.api(kubeapi)?;
+            let name = object.name();
+            let live = api.get_opt(&name).await.map_err(|err| object.error(err))?;
+            let merged = api
+                .patch(&name, &pp, &api::Patch::Apply(object.object()))
+                .await
+                .map_err(|err| object.error(err))?;
+            compared.push(Compared {
+                name: file_name(&object, &merged),
+                live: live.map(comparable).transpose()?.unwrap_or_default(),
+                merged: comparable(merged)?,
+            });
+        }
+
+        match self.external.as_deref() {
+            Some(program) => external(program, &compared),
+            None => builtin(&compared),
+        }
+    }
+}
+
+/// Same as kubectl, `group.version.kind.namespace.name`
+fn file_name(object: &ManifestObject, merged: &api::DynamicObject) -> String {
+    let resource = object.resource();
+    let api_version = resource.api_version.replace('/', ".");
+    let name = merged.name_any();
+    match merged.namespace() {
+        Some(namespace) => format!("{api_version}.{}.{namespace}.{name}", resource.kind),
+        None => format!("{api_version}.{}.{name}", resource.kind),
+    }
+}
+
+/// YAML without the fields that change on every write
+fn comparable(object: api::DynamicObject) -> RkResult<String> {
+    let mut object = json::to_value(object).map_err(kube::Error::SerdeError)?;
+    if let Some(object) = object.as_object_mut() {
+        object.remove("status");
+        if let Some(metadata) = object
+            .get_mut("metadata")
+            .and_then(json::Value::as_object_mut)
+        {
+            metadata.remove("managedFields");
+            metadata.remove("resourceVersion");
+            metadata.remove("generation");
+        }
+    }
+    serde_yaml::to_string(&object).map_err(|err| io::Error::other(err).into())
+}
+
+fn builtin(compared: &[Compared]) -> RkResult<()> {
+    let colored = io::stdout().is_terminal();
+    let mut different = false;
+    for item in compared.iter().filter(|item| item.live != item.merged) {
+        different = true;
+        let live = format!("live/{}", item.name);
+        let merged = format!("merged/{}", item.name);
+        let diff = TextDiff::from_lines(&item.live, &item.merged);
+        println!("diff -u -N {live} {merged}");
+        let unified = diff
+            .unified_diff()
+            .context_radius(3)
+            .header(&live, &merged)
+            .to_string();
+        for line in unified.lines() {
+            if !colored {
+                println!("{line}");
+            } else if line.starts_with("---") || line.starts_with("+++") {
+                println!("{}", line.bold());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with("@@") {
+                println!("{}", line.cyan());
+            } else {
+                println!("{line}");
+            }
+        }
+    }
+
+    if different {
+        Err(RkError::Exit(Diff::DIFFERENT))
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes both versions into `LIVE-*` and `MERGED-*` directories and hands them to `program`,
+/// exiting with its exit code
+fn external(program: &str, compared: &[Compared]) -> RkResult<()> {
+    let mut words = program.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| RkError::invalid_arguments("the external diff program is empty"))?;
+
+    let live = tempfile::Builder::new().prefix("LIVE-").tempdir()?;
+    let merged = tempfile::Builder::new().prefix("MERGED-").tempdir()?;
+    for item in compared {
+        fs::write(live.path().join(&item.name), &item.live)?;
+        fs::write(merged.path().join(&item.name), &item.merged)?;
+    }
+
+    let status = process::Command::new(program)
+        .args(words)
+        .arg(live.path())
+        .arg(merged.path())
+        .status()?;
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(code) => Err(RkError::Exit(code)),
+        // Killed by a signal
+        None => Err(RkError::Exit(Diff::FAILED)),
+    }
+}
+
+/// Same as kubectl, 1 only when differences were found, above 1 for any failure
+fn exit_code(err: &RkError) -> i32 {
+    match err {
+        RkError::Exit(code) => *code,
+        _ => Diff::FAILED,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_not_differences() {
+        assert_eq!(exit_code(&RkError::Exit(Diff::DIFFERENT)), 1);
+        assert_eq!(exit_code(&RkError::Exit(3)), 3);
+        assert_eq!(exit_code(&RkError::not_found("web not found")), 2);
+        assert_eq!(exit_code(&io::Error::other("broken pipe").into()), 2);
+        let status = kube::core::Status::failure("forbidden", "Forbidden").with_code(403);
+        let api = kube::Error::Api(status.boxed());
+        assert_eq!(exit_code(&api.into()), 2);
+    }
+
+    #[test]
+    fn comparable_strips_noise() {
+        let object = json::from_value::<api::DynamicObject>(json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": "web",
+                "generation": 3,
+                "resourceVersion": "42",
+                "managedFields": [{"manager": "kubectl"}],
+            },
+            "data": {"key": "value"},
+            "status": {"phase": "Active"},
+        }))
+        .unwrap();
+        let yaml = comparable(object).unwrap();
+        assert!(yaml.contains("key: value"));
+        for noise in ["generation", "resourceVersion", "managedFields", "status"] {
+            assert!(!yaml.contains(noise), "{noise} in {yaml}");
+        }
+    }
+}
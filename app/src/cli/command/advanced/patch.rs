@@ -0,0 +1,273 @@
+use clap::ValueEnum;
+use rkubectl_resource::MergeKeys;
+use rkubectl_resource::PatchStrategy;
+use rkubectl_resource::apply_patch;
+
+use super::*;
+
+/// Update fields of a resource using strategic merge patch, a JSON merge patch, or a JSON patch.
+///
+///  JSON and YAML formats are accepted.
+///
+///  With --dry-run=client the patch is applied locally to the live object, strategic merge
+/// patches going by the patch merge keys the server publishes for its kind.
+///
+/// Examples:
+///   # Partially update a node using a strategic merge patch, specifying the patch as JSON
+///   kubectl patch node k8s-node-1 -p '{"spec":{"unschedulable":true}}'
+///
+///   # Partially update a node identified by the type and name specified in "node.json" using strategic merge patch
+///   kubectl patch node/k8s-node-1 --patch-file node.json
+///
+///   # Update a container's image using a JSON patch with positional arrays
+///   kubectl patch pod valid-pod --type='json' -p='[{"op": "replace", "path": "/spec/containers/0/image", "value":"new image"}]'
+///
+///   # Update a deployment's replicas through the 'scale' subresource using a merge patch
+///   kubectl patch deployment nginx-deployment --subresource='scale' --type='merge' -p '{"spec":{"replicas":2}}'
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Patch {
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-patch")]
+    field_manager: String,
+
+    /// The patch to be applied to the resource JSON file.
+    #[arg(
+        short,
+        long,
+        required_unless_present = "patch_file",
+        conflicts_with = "patch_file"
+    )]
+    patch: Option<String>,
+
+    /// A file containing a patch to be applied to the resource.
+    #[arg(long)]
+    patch_file: Option<PathBuf>,
+
+    /// If specified, patch will operate on the subresource of the requested object.
+    #[arg(long, value_enum)]
+    subresource: Option<Subresource>,
+
+    /// The type of patch being provided.
+    #[arg(long = "type", value_enum, default_value_t = PatchType::Strategic)]
+    patch_type: PatchType,
+
+    #[command(flatten)]
+    params: ShowParams,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum PatchType {
+    Json,
+    Merge,
+    Strategic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Subresource {
+    Status,
+    Scale,
+}
+
+impl Patch {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let namespace = kubeapi.show_namespace();
+
+        let patch = self.patch()?;
+        let pp = kubeapi.patch_params(&self.field_manager, self.dry_run);
+        let resources = ResourceArg::from_strings(&self.resources, kubeapi)
+            .map_err(RkError::invalid_arguments)?;
+        for resource in resources {
+            let ResourceArg::NamedResource(resource) = resource else {
+                return Err(RkError::invalid_arguments(
+                    "resource(s) were provided, but no name was specified",
+                ));
+            };
+            let (scope, dyntype) = resource.resource().api_resource();
+            let api = kubeapi.object_api(scope, &dyntype, None)?;
+            let name = resource.name();
+
+            let patched = if self.dry_run == DryRun::Client {
+                let live = match self.subresource {
+                    Some(subresource) => api.get_subresource(subresource.as_str(), name).await?,
+                    None => api.get(name).await?,
+                };
+                let types = live.types.clone().unwrap_or_default();
+                let keys = MergeKeys::new(kubeapi, &types.api_version, &types.kind).await;
+                local_patch(live, &patch, &keys)?
+            } else {
+                match self.subresource {
+                    Some(subresource) => {
+                        api.patch_subresource(subresource.as_str(), name, &pp, &patch)
+                            .await?
+                    }
+                    None => api.patch(name, &pp, &patch).await?,
+                }
+            };
+
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => {
+                    println!("{resource} patched{}", self.dry_run.suffix());
+                }
+                output => println!("{}", patched.output(namespace, self.params, output)),
+            }
+        }
+        Ok(())
+    }
+
+    /// The patch from -p or --patch-file, given as either JSON or YAML
+    fn patch(&self) -> RkResult<api::Patch<json::Value>> {
+        // One of them is required, and they conflict
+        let text = match &self.patch {
+            Some(patch) => patch.clone(),
+            None => self
+                .patch_file
+                .as_ref()
+                .map(fs::read_to_string)
+                .transpose()?
+                .unwrap_or_default(),
+        };
+        let invalid = |err: &dyn std::error::Error| {
+            RkError::invalid_arguments(format!("unable to parse \"{text}\": {err}"))
+        };
+        let value = serde_yaml::from_str::<json::Value>(&text).map_err(|err| invalid(&err))?;
+        let patch = match self.patch_type {
+            PatchType::Json => {
+                let patch =
+                    json::from_value::<json_patch::Patch>(value).map_err(|err| invalid(&err))?;
+                api::Patch::Json(patch)
+            }
+            PatchType::Merge => api::Patch::Merge(value),
+            PatchType::Strategic => api::Patch::Strategic(value),
+        };
+        Ok(patch)
+    }
+}
+
+impl Subresource {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::Scale => "scale",
+        }
+    }
+}
+
+/// `object` as the server would leave it after `patch`
+fn local_patch(
+    object: api::DynamicObject,
+    patch: &api::Patch<json::Value>,
+    keys: &MergeKeys,
+) -> RkResult<api::DynamicObject> {
+    let mut value = json::to_value(object).map_err(kube::Error::SerdeError)?;
+    match patch {
+        api::Patch::Json(patch) => json_patch::patch(&mut value, patch)
+            .map_err(|err| RkError::invalid_arguments(err.to_string()))?,
+        api::Patch::Merge(patch) => json_patch::merge(&mut value, patch),
+        api::Patch::Strategic(patch) => {
+            value = apply_patch(&value, patch, PatchStrategy::Strategic(keys));
+        }
+        _ => {}
+    }
+    json::from_value(value).map_err(|err| kube::Error::SerdeError(err).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        patch: Patch,
+    }
+
+    fn patch(args: &[&str]) -> RkResult<api::Patch<json::Value>> {
+        let args = ["patch", "pod/web"].iter().chain(args);
+        Command::parse_from(args).patch.patch()
+    }
+
+    fn pod() -> api::DynamicObject {
+        json::from_value(json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": {"name": "web"},
+            "spec": {"containers": [
+                {"name": "web", "image": "nginx:1"},
+                {"name": "sidecar", "image": "envoy:1"}
+            ]}
+        }))
+        .unwrap()
+    }
+
+    /// Containers merged by name, as the server publishes for pods
+    fn keys() -> MergeKeys {
+        let document = json::json!({"components": {"schemas": {
+            "io.k8s.api.core.v1.Pod": {
+                "properties": {"spec": {"$ref": "#/components/schemas/io.k8s.api.core.v1.PodSpec"}},
+                "x-kubernetes-group-version-kind": [{"group": "", "kind": "Pod", "version": "v1"}]
+            },
+            "io.k8s.api.core.v1.PodSpec": {"properties": {"containers": {
+                "items": {"type": "object"},
+                "type": "array",
+                "x-kubernetes-patch-merge-key": "name",
+                "x-kubernetes-patch-strategy": "merge"
+            }}}
+        }}});
+        MergeKeys::from_document(document, "", "v1", "Pod")
+    }
+
+    #[test]
+    fn patch_types() {
+        let yaml = patch(&["-p", "spec:\n  replicas: 2"]).unwrap();
+        assert!(matches!(yaml, api::Patch::Strategic(value) if value["spec"]["replicas"] == 2));
+
+        let merge = patch(&["--type=merge", "-p", r#"{"spec":{"replicas":2}}"#]).unwrap();
+        assert!(matches!(merge, api::Patch::Merge(_)));
+
+        let json = patch(&["--type=json", "-p", r#"[{"op":"remove","path":"/spec"}]"#]);
+        assert!(matches!(json.unwrap(), api::Patch::Json(_)));
+
+        let invalid = patch(&["--type=json", "-p", r#"{"spec":{}}"#]);
+        assert!(matches!(invalid, Err(RkError::InvalidArguments(_))));
+    }
+
+    #[test]
+    fn local_patches() {
+        let image = json::json!({"spec": {"containers": [{"name": "web", "image": "nginx:2"}]}});
+        let images = |patch| {
+            let patched = local_patch(pod(), &patch, &keys()).unwrap();
+            patched.data["spec"]["containers"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|container| container["image"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Strategic merges the containers by name, a merge patch replaces the list
+        assert_eq!(
+            images(api::Patch::Strategic(image.clone())),
+            ["nginx:2", "envoy:1"]
+        );
+        assert_eq!(images(api::Patch::Merge(image)), ["nginx:2"]);
+
+        let replace = json::from_value(json::json!([
+            {"op": "replace", "path": "/spec/containers/1/image", "value": "envoy:2"}
+        ]))
+        .unwrap();
+        assert_eq!(images(api::Patch::Json(replace)), ["nginx:1", "envoy:2"]);
+    }
+}
@@ -0,0 +1,203 @@
+use rkubectl_args::Duration;
+use rkubectl_resource::ManifestObject;
+
+use super::delete::GracePeriod;
+use super::*;
+
+/// Replace a resource by file name or stdin.
+///
+///  JSON and YAML formats are accepted. If replacing an existing resource, the complete resource spec must be provided.
+/// The resourceVersion of the current object is used when the manifest does not carry one.
+///
+///  With --force the resource is deleted, and created again once the deletion completed. This is needed to change
+/// immutable fields, e.g. the template of a Job.
+///
+/// Examples:
+///   # Replace a pod using the data in pod.json
+///   kubectl replace -f ./pod.json
+///
+///   # Replace a pod based on the JSON passed into stdin
+///   cat pod.json | kubectl replace -f -
+///
+///   # Force replace, delete and then re-create the resource
+///   kubectl replace --force -f ./pod.json
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Replace {
+    /// Selects the deletion cascading strategy for the dependents, with --force.
+    #[arg(long, value_enum, default_value_t = Cascade::Background)]
+    cascade: Cascade,
+
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-replace")]
+    field_manager: String,
+
+    /// The files that contain the configurations to replace.
+    #[arg(short, long, required = true)]
+    filename: Vec<String>,
+
+    #[command(flatten)]
+    grace_period: GracePeriod,
+
+    /// Process the directory used in -f, --filename recursively.
+    /// Useful when you want to manage related manifests organized within the same directory.
+    #[arg(long, short = 'R')]
+    recursive: bool,
+
+    /// If true, the configuration of current object will be saved in its annotation.
+    /// Otherwise, the annotation will be unchanged.
+    /// This flag is useful when you want to perform kubectl apply on this object in the future.
+    #[arg(long)]
+    save_config: bool,
+
+    /// The length of time to wait before giving up on a delete, zero means wait forever.
+    #[arg(long, value_parser = Duration::value_parser(), default_value = "0s")]
+    timeout: Duration,
+
+    #[command(flatten)]
+    params: ShowParams,
+}
+
+impl Replace {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let namespace = kubeapi.show_namespace();
+        if !self.grace_period.force() && self.grace_period.is_set() {
+            return Err(RkError::invalid_arguments(
+                "--grace-period must have --force specified",
+            ));
+        }
+        self.grace_period.check()?;
+
+        for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+            let object = ManifestObject::new(kubeapi, manifest).await?;
+            let api = object.api(kubeapi)?;
+            let mut data = object.object().clone();
+            if self.save_config {
+                rkubectl_resource::save_config(&mut data).map_err(kube::Error::SerdeError)?;
+            }
+
+            let replaced = if self.dry_run == DryRun::Client {
+                data
+            } else if self.grace_period.force() {
+                self.recreate(kubeapi, &api, &object, data).await?
+            } else {
+                self.replace(kubeapi, &api, &object, data)
+                    .await
+                    .map_err(|err| object.error(err))?
+            };
+
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => {
+                    println!("{object} replaced{}", self.dry_run.suffix());
+                }
+                output => println!("{}", replaced.output(namespace, self.params, output)),
+            }
+        }
+        Ok(())
+    }
+
+    /// PUT the object, over the current version unless the manifest names one
+    async fn replace(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        object: &ManifestObject,
+        mut data: api::DynamicObject,
+    ) -> kube::Result<api::DynamicObject> {
+        let name = object.name();
+        if data.metadata.resource_version.is_none() {
+            let current = api.get(&name).await?;
+            data.metadata.resource_version = current.metadata.resource_version;
+        }
+        let pp = kubeapi.post_params_with_dry_run(&self.field_manager, self.dry_run);
+        api.replace(&name, &pp, &data).await
+    }
+
+    fn delete_params(&self, kubeapi: &Kubeapi) -> api::DeleteParams {
+        self.grace_period
+            .delete_params(kubeapi, self.cascade, self.dry_run)
+    }
+
+    /// Delete the object, wait for it to be gone and create it again
+    async fn recreate(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        object: &ManifestObject,
+        mut data: api::DynamicObject,
+    ) -> RkResult<api::DynamicObject> {
+        let name = object.name();
+        let dp = self.delete_params(kubeapi);
+
+        match api.delete(&name, &dp).await {
+            Ok(deleted) => {
+                println!("{object} deleted{}", self.dry_run.suffix());
+                if self.dry_run == DryRun::Server {
+                    // Nothing was deleted, validate the new object against the current one instead
+                    return self
+                        .replace(kubeapi, api, object, data)
+                        .await
+                        .map_err(|err| object.error(err).into());
+                }
+                let uid = deleted.left().and_then(|deleted| deleted.metadata.uid);
+                let timeout = (!self.timeout.is_zero()).then(|| self.timeout.duration());
+                let gone = kubeapi
+                    .wait_deleted(api, &name, uid.as_deref(), timeout)
+                    .await
+                    .map_err(|err| object.error(err))?;
+                if !gone {
+                    return Err(RkError::Timeout(format!(
+                        "timed out waiting for the deletion of {object}"
+                    )));
+                }
+            }
+            Err(kube::Error::Api(status)) if status.is_not_found() => {}
+            Err(err) => return Err(object.error(err).into()),
+        }
+
+        data.metadata.resource_version = None;
+        data.metadata.uid = None;
+        let pp = kubeapi.post_params_with_dry_run(&self.field_manager, self.dry_run);
+        let created = api
+            .create(&pp, &data)
+            .await
+            .map_err(|err| object.error(err))?;
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        replace: Replace,
+    }
+
+    #[test]
+    fn grace_period() {
+        let kubeapi = Kubeapi::local();
+        let grace_period = |args: &[&str]| {
+            let args = ["replace", "--force", "-f", "pod.yaml"].iter().chain(args);
+            Command::parse_from(args)
+                .replace
+                .delete_params(&kubeapi)
+                .grace_period_seconds
+        };
+
+        assert_eq!(grace_period(&[]), Some(0));
+        assert_eq!(grace_period(&["--now"]), Some(1));
+        assert_eq!(grace_period(&["--grace-period=5"]), Some(5));
+    }
+}
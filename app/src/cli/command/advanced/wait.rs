@@ -0,0 +1,96 @@
+use rkubectl_args::Duration;
+use rkubectl_args::WaitFor;
+
+use super::*;
+
+/// Experimental: Wait for a specific condition on one or many resources.
+///
+///  The command takes multiple resources and waits until the specified condition is seen in the Status field of
+/// every given resource.
+///
+///  Alternatively, the command can wait for the given set of resources to be created or deleted by providing the
+/// "create" or "delete" keyword as the value to the --for flag.
+///
+///  A successful message will be printed to stdout indicating when the specified condition has been met. You can use
+/// -o option to change to output destination.
+///
+/// Examples:
+///   # Wait for the pod "busybox1" to contain the status condition of type "Ready"
+///   kubectl wait --for=condition=Ready pod/busybox1
+///
+///   # The default value of status condition is true; you can wait for other targets after an equal delimiter
+///   kubectl wait --for=condition=Ready=false pod/busybox1
+///
+///   # Wait for the pod "busybox1" to contain the status phase to be "Running"
+///   kubectl wait --for=jsonpath='{.status.phase}'=Running pod/busybox1
+///
+///   # Wait for all pods labelled app=web to be ready
+///   kubectl wait --for=condition=Ready pods -l app=web
+///
+///   # Wait for the pod "busybox1" to be deleted, with a timeout of 60s, after having issued the "delete" command
+///   kubectl delete pod/busybox1
+///   kubectl wait --for=delete pod/busybox1 --timeout=60s
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Wait {
+    /// Select all resources in the namespace of the specified resource types.
+    #[arg(long)]
+    all: bool,
+
+    /// The condition to wait on: [create|delete|condition=condition-name[=condition-value]|jsonpath='{JSONPath expression}'=[JSONPath value]].
+    /// The default condition-value is true. Condition values are compared after Unicode simple case folding.
+    #[arg(long = "for", value_parser = WaitFor::value_parser())]
+    condition: WaitFor,
+
+    /// The length of time to wait before giving up. Zero means check once and don't wait.
+    #[arg(long, value_parser = Duration::value_parser(), default_value = "30s")]
+    timeout: Duration,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl Wait {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let resources = ResourceArg::from_strings(&self.resources, kubeapi)
+            .map_err(RkError::invalid_arguments)?;
+
+        let targets = ResourceArg::select(resources, kubeapi, self.all).await?;
+        if targets.is_empty() {
+            return Err(RkError::not_found("no matching resources found"));
+        }
+
+        let deadline = tokio::time::Instant::now() + self.timeout.duration();
+        for (resource, namespace) in targets {
+            let (scope, ref dyntype) = resource.resource().api_resource();
+            let api = kubeapi.object_api(scope, dyntype, namespace.as_deref())?;
+            let name = resource.name();
+
+            let needs_object = !matches!(self.condition, WaitFor::Delete | WaitFor::Create);
+            if needs_object && api.get_opt(name).await?.is_none() {
+                return Err(RkError::not_found(format!("{resource} not found")));
+            }
+
+            let met = |object: Option<&api::DynamicObject>| {
+                let object = object.map(|object| json::to_value(object).unwrap_or_default());
+                self.condition.is_met(object.as_ref())
+            };
+            let timeout = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if !kubeapi.wait_until(&api, name, met, Some(timeout)).await? {
+                return Err(RkError::Timeout(format!(
+                    "timed out waiting for the condition on {resource}"
+                )));
+            }
+
+            match self.condition {
+                WaitFor::Delete => println!("{resource} deleted"),
+                WaitFor::Create => println!("{resource} created"),
+                WaitFor::Condition { .. } | WaitFor::JsonPath { .. } => {
+                    println!("{resource} condition met");
+                }
+            }
+        }
+        Ok(())
+    }
+}
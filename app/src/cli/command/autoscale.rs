@@ -0,0 +1,235 @@
+use super::*;
+
+/// Creates an autoscaler that automatically chooses and sets the number of pods that run in a Kubernetes cluster.
+///
+///  Looks up a deployment, replica set, stateful set, or replication controller by name and creates an autoscaler that
+/// uses the given resource as a reference. An autoscaler can automatically increase or decrease number of pods
+/// deployed within the system as needed. Any resource exposing the scale subresource can be autoscaled.
+///
+///  The autoscaler is created through the autoscaling/v2 API.
+///
+/// Examples:
+///   # Auto scale a deployment "foo", with the number of pods between 2 and 10, no target CPU utilization specified so a default autoscaling policy will be used
+///   kubectl autoscale deployment foo --min=2 --max=10
+///
+///   # Auto scale a replication controller "foo", with the number of pods between 1 and 5, target CPU utilization at 80%
+///   kubectl autoscale rc foo --max=5 --cpu-percent=80
+///
+///   # Auto scale a deployment "bar", targeting both 70% CPU and 60% memory utilization
+///   kubectl autoscale deployment/bar --max=8 --cpu-percent=70 --memory-percent=60
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Autoscale {
+    /// The target average CPU utilization (represented as a percent of requested CPU) over all the pods. If it's not
+    /// specified or negative, a default autoscaling policy will be used.
+    #[arg(long, default_value_t = -1, allow_negative_numbers = true)]
+    cpu_percent: i32,
+
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-autoscale")]
+    field_manager: String,
+
+    /// The upper limit for the number of pods that can be set by the autoscaler. Required.
+    #[arg(long, required = true, allow_negative_numbers = true)]
+    max: i32,
+
+    /// The target average memory utilization (represented as a percent of requested memory) over all the pods.
+    /// If it's not specified or negative, memory utilization is not targeted.
+    #[arg(long, default_value_t = -1, allow_negative_numbers = true)]
+    memory_percent: i32,
+
+    /// The lower limit for the number of pods that can be set by the autoscaler. If it's not specified or negative, the
+    /// server will apply a default value.
+    #[arg(long, default_value_t = -1, allow_negative_numbers = true)]
+    min: i32,
+
+    /// The name for the newly created object. If not specified, the name of the input resource will be used.
+    #[arg(long)]
+    name: Option<String>,
+
+    /// If true, the configuration of current object will be saved in its annotation.
+    /// Otherwise, the annotation will be unchanged.
+    /// This flag is useful when you want to perform kubectl apply on this object in the future.
+    #[arg(long)]
+    save_config: bool,
+
+    #[command(flatten)]
+    params: ShowParams,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl Autoscale {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let namespace = kubeapi.show_namespace();
+        self.validate()?;
+
+        let pp = kubeapi.post_params_with_dry_run(&self.field_manager, self.dry_run);
+        let resources = ResourceArg::from_strings(&self.resources, kubeapi)
+            .map_err(RkError::invalid_arguments)?;
+        for resource in resources {
+            let ResourceArg::NamedResource(resource) = resource else {
+                return Err(RkError::invalid_arguments(
+                    "resource(s) were provided, but no name was specified",
+                ));
+            };
+            if !resource.resource().has_scale(kubeapi) {
+                return Err(RkError::invalid_arguments(format!(
+                    "cannot autoscale {resource}, it does not expose the scale subresource"
+                )));
+            }
+            let (scope, ref dyntype) = resource.resource().api_resource();
+            let target = kubeapi
+                .object_api(scope, dyntype, None)?
+                .get(resource.name())
+                .await?;
+
+            let mut hpa = self.hpa(dyntype, &target.name_any());
+            if self.save_config {
+                rkubectl_resource::save_config(&mut hpa).map_err(kube::Error::SerdeError)?;
+            }
+            let hpa = if self.dry_run == DryRun::Client {
+                hpa
+            } else {
+                kubeapi
+                    .horizontalpodautoscalers()?
+                    .create(&pp, &hpa)
+                    .await?
+            };
+
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => println!(
+                    "horizontalpodautoscaler.autoscaling/{} autoscaled{}",
+                    hpa.name_any(),
+                    self.dry_run.suffix()
+                ),
+                output => {
+                    let hpa = json::to_value(&hpa)
+                        .and_then(json::from_value::<api::DynamicObject>)
+                        .map_err(kube::Error::SerdeError)?;
+                    println!("{}", hpa.output(namespace, self.params, output));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> RkResult<()> {
+        if self.max < 1 {
+            return Err(RkError::invalid_arguments(format!(
+                "--max=MAXPODS is required and must be at least 1, max: {}",
+                self.max
+            )));
+        }
+        if self.max < self.min {
+            return Err(RkError::invalid_arguments(format!(
+                "--max=MAXPODS must be larger or equal to --min=MINPODS, max: {}, min: {}",
+                self.max, self.min
+            )));
+        }
+        Ok(())
+    }
+
+    /// The autoscaler for the `name` object of `dyntype`, leaving unset what the server defaults
+    fn hpa(
+        &self,
+        dyntype: &api::ApiResource,
+        name: &str,
+    ) -> autoscalingv2::HorizontalPodAutoscaler {
+        let metrics = [("cpu", self.cpu_percent), ("memory", self.memory_percent)]
+            .into_iter()
+            .filter(|(_, percent)| *percent >= 0)
+            .map(|(resource, percent)| autoscalingv2::MetricSpec {
+                type_: "Resource".to_string(),
+                resource: Some(autoscalingv2::ResourceMetricSource {
+                    name: resource.to_string(),
+                    target: autoscalingv2::MetricTarget {
+                        type_: "Utilization".to_string(),
+                        average_utilization: Some(percent),
+                        ..default()
+                    },
+                }),
+                ..default()
+            })
+            .collect::<Vec<_>>();
+
+        autoscalingv2::HorizontalPodAutoscaler {
+            metadata: metav1::ObjectMeta {
+                name: Some(self.name.clone().unwrap_or_else(|| name.to_string())),
+                ..default()
+            },
+            spec: Some(autoscalingv2::HorizontalPodAutoscalerSpec {
+                scale_target_ref: autoscalingv2::CrossVersionObjectReference {
+                    api_version: Some(dyntype.api_version.clone()),
+                    kind: dyntype.kind.clone(),
+                    name: name.to_string(),
+                },
+                min_replicas: (self.min >= 0).then_some(self.min),
+                max_replicas: self.max,
+                metrics: (!metrics.is_empty()).then_some(metrics),
+                ..default()
+            }),
+            ..default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        autoscale: Autoscale,
+    }
+
+    fn autoscale(args: &[&str]) -> Autoscale {
+        let args = ["autoscale", "deployment/web"].iter().chain(args);
+        Command::parse_from(args).autoscale
+    }
+
+    #[test]
+    fn horizontal_pod_autoscaler() {
+        let dyntype = api::ApiResource::erase::<appsv1::Deployment>(&());
+        let hpa = autoscale(&["--min=2", "--max=10", "--memory-percent=60"]).hpa(&dyntype, "web");
+        assert_eq!(
+            json::to_value(&hpa).unwrap(),
+            json::json!({
+                "apiVersion": "autoscaling/v2",
+                "kind": "HorizontalPodAutoscaler",
+                "metadata": {"name": "web"},
+                "spec": {
+                    "scaleTargetRef": {"apiVersion": "apps/v1", "kind": "Deployment", "name": "web"},
+                    "minReplicas": 2,
+                    "maxReplicas": 10,
+                    "metrics": [{
+                        "type": "Resource",
+                        "resource": {
+                            "name": "memory",
+                            "target": {"type": "Utilization", "averageUtilization": 60}
+                        }
+                    }]
+                }
+            })
+        );
+
+        assert!(autoscale(&["--max=0"]).validate().is_err());
+        assert!(autoscale(&["--min=3", "--max=2"]).validate().is_err());
+        assert!(
+            autoscale(&["--max=5", "--cpu-percent=80"])
+                .validate()
+                .is_ok()
+        );
+    }
+}
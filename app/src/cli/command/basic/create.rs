@@ -1,7 +1,10 @@
 use super::*;
 
+use rkubectl_resource::ManifestObject;
 use rkubectl_ui::Created;
 
+use super::edit::Editor;
+
 use clusterrole::CreateClusterRole;
 use namespace::CreateNamespace;
 use secret::CreateSecret;
@@ -47,7 +50,7 @@ pub struct Create {
 
     /// Filename, directory, or URL to files to use to create the resource
     #[arg(short, long, required = true)]
-    filename: Option<String>,
+    filename: Vec<String>,
 
     /// Process the directory used in -f, --filename recursively.
     /// Useful when you want to manage related manifests organized within the same directory.
@@ -112,29 +115,67 @@ pub enum CreateResource {
 impl Create {
     pub async fn exec(self, context: &Context) -> RkResult<()> {
         let kubeapi = context.kubeapi();
-        let created = if let Some(filename) = &self.filename {
-            self.create_from_file(filename, kubeapi).await
-        } else {
-            self.create_resource(kubeapi).await
-        }?;
-
         let namespace = kubeapi.show_namespace();
         let params = self.params();
         let output = context.output_deprecated();
+        if !self.filename.is_empty() {
+            return self.create_from_files(kubeapi, namespace, output).await;
+        }
+
+        let created = self.create_resource(kubeapi).await?;
         println!("{}", created.output(namespace, params, output));
         Ok(())
     }
 
-    async fn create_from_file(&self, filename: &str, kubeapi: &Kubeapi) -> RkResult<Box<dyn Show>> {
-        let pp = kubeapi.post_params_with_manager(&self.field_manager);
-        println!("Creating from {filename} [{pp:?}]");
-        Err(RkError::todo())
+    async fn create_from_files(
+        &self,
+        kubeapi: &Kubeapi,
+        namespace: bool,
+        output: OutputFormat,
+    ) -> RkResult<()> {
+        let pp = kubeapi.post_params_with_dry_run(&self.field_manager, self.dry_run);
+        for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+            let object = ManifestObject::new(kubeapi, manifest).await?;
+            let api = object.api(kubeapi)?;
+            let create = async |data| -> RkResult<api::DynamicObject> {
+                let data = saved_config(data, self.save_config)?;
+                if self.dry_run == DryRun::Client {
+                    Ok(data)
+                } else {
+                    Ok(api.create(&pp, &data).await?)
+                }
+            };
+
+            let created = if self.edit {
+                let editor = Editor::new(output, self.params());
+                let Some(created) = editor.edit(object.object(), create).await? else {
+                    println!("Edit cancelled, no changes made.");
+                    continue;
+                };
+                created
+            } else {
+                create(object.object().clone())
+                    .await
+                    .map_err(|err| match err {
+                        RkError::Kube(err) => object.error(err).into(),
+                        err => err,
+                    })?
+            };
+
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => {
+                    println!("{object} created{}", self.dry_run.suffix());
+                }
+                output => println!("{}", created.output(namespace, self.params(), output)),
+            }
+        }
+        Ok(())
     }
 
     async fn create_resource(&self, kubeapi: &Kubeapi) -> RkResult<Box<dyn Show>> {
         if let Some(command) = &self.command {
             let pp = kubeapi.post_params_with_manager(&self.field_manager);
-            command.exec(kubeapi, &pp).await
+            command.exec(kubeapi, &pp, self.save_config).await
         } else {
             unreachable!()
         }
@@ -149,25 +190,41 @@ impl Create {
 }
 
 impl CreateResource {
-    pub async fn exec(&self, kubeapi: &Kubeapi, pp: &api::PostParams) -> RkResult<Box<dyn Show>> {
+    pub async fn exec(
+        &self,
+        kubeapi: &Kubeapi,
+        pp: &api::PostParams,
+        save_config: bool,
+    ) -> RkResult<Box<dyn Show>> {
         match self {
-            Self::ClusterRole(cluster_role) => cluster_role.exec(kubeapi, pp).await,
+            Self::ClusterRole(cluster_role) => cluster_role.exec(kubeapi, pp, save_config).await,
             Self::ClusterRoleBinding => Err(RkError::todo()),
             Self::ConfigMap => Err(RkError::todo()),
             Self::CronJob => Err(RkError::todo()),
             Self::Deployment => Err(RkError::todo()),
             Self::Ingress => Err(RkError::todo()),
             Self::Job => Err(RkError::todo()),
-            Self::Namespace(namespace) => namespace.exec(kubeapi, pp).await,
+            Self::Namespace(namespace) => namespace.exec(kubeapi, pp, save_config).await,
             Self::PodDisruptionBudget => Err(RkError::todo()),
             Self::PriorityClass => Err(RkError::todo()),
             Self::Quota => Err(RkError::todo()),
             Self::Role => Err(RkError::todo()),
             Self::RoleBinding => Err(RkError::todo()),
-            Self::Secret(secret) => secret.exec(kubeapi, pp).await,
+            Self::Secret(secret) => secret.exec(kubeapi, pp, save_config).await,
             Self::Service => Err(RkError::todo()),
             Self::ServiceAccount => Err(RkError::todo()),
             Self::Token => Err(RkError::todo()),
         }
     }
 }
+
+/// With --save-config, records `data` as its last applied configuration for `apply --server-side=false`
+fn saved_config<K>(mut data: K, save_config: bool) -> RkResult<K>
+where
+    K: kube::Resource + serde::Serialize,
+{
+    if save_config {
+        rkubectl_resource::save_config(&mut data).map_err(kube::Error::SerdeError)?;
+    }
+    Ok(data)
+}
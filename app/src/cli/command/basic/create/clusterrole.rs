@@ -57,8 +57,13 @@ struct PolicyRulesArgs {
 }
 
 impl CreateClusterRole {
-    pub async fn exec(&self, kubeapi: &Kubeapi, pp: &api::PostParams) -> RkResult<Box<dyn Show>> {
-        let data = self.cluster_role();
+    pub async fn exec(
+        &self,
+        kubeapi: &Kubeapi,
+        pp: &api::PostParams,
+        save_config: bool,
+    ) -> RkResult<Box<dyn Show>> {
+        let data = saved_config(self.cluster_role(), save_config)?;
 
         let k = kubeapi
             .clusterroles()?
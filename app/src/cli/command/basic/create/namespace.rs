@@ -15,8 +15,13 @@ pub struct CreateNamespace {
 }
 
 impl CreateNamespace {
-    pub async fn exec(&self, kubeapi: &Kubeapi, pp: &api::PostParams) -> RkResult<Box<dyn Show>> {
-        let data = corev1::Namespace::new(&self.name);
+    pub async fn exec(
+        &self,
+        kubeapi: &Kubeapi,
+        pp: &api::PostParams,
+        save_config: bool,
+    ) -> RkResult<Box<dyn Show>> {
+        let data = saved_config(corev1::Namespace::new(&self.name), save_config)?;
         let k = kubeapi
             .namespaces()?
             .create(pp, &data)
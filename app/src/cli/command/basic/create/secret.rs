@@ -23,13 +23,19 @@ pub enum CreateSecret {
 }
 
 impl CreateSecret {
-    pub async fn exec(&self, kubeapi: &Kubeapi, pp: &api::PostParams) -> RkResult<Box<dyn Show>> {
+    pub async fn exec(
+        &self,
+        kubeapi: &Kubeapi,
+        pp: &api::PostParams,
+        save_config: bool,
+    ) -> RkResult<Box<dyn Show>> {
         trace!(?kubeapi, ?pp);
         let data = match self {
             Self::DockerRegistry(docker_registry) => docker_registry.secret().await,
             Self::Generic(generic) => generic.secret().await,
             Self::Tls(tls) => tls.secret(),
         }?;
+        let data = saved_config(data, save_config)?;
 
         let k = kubeapi
             .secrets()?
@@ -64,9 +64,7 @@ impl ClusterInfo {
 
     async fn info(&self, context: &Context) -> RkResult<()> {
         let kubeapi = context.kubeapi().clone().with_namespace(Namespace::All);
-        let lp = kubeapi
-            .list_params()
-            .labels_from(&ClusterService::selector());
+        let lp = kubeapi.list_params_with_selector(&ClusterService::selector());
         let services = kubeapi
             .services()?
             .list(&lp)
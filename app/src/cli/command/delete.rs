@@ -1,4 +1,11 @@
+use std::io::Write;
+
+use clap::ArgAction;
 use clap::builder::ArgPredicate;
+use rkubectl_args::Duration;
+use rkubectl_resource::ManifestObject;
+use rkubectl_resource::NamedResource;
+use rkubectl_resource::Resource;
 
 use super::*;
 
@@ -150,13 +157,10 @@ pub struct Delete {
 
     /// Filename, directory, or URL to files to use to create the resource
     #[arg(short, long, required_unless_present("TYPE"))]
-    filename: Option<String>,
+    filename: Vec<String>,
 
-    /// If true, immediately remove resources from API and bypass graceful deletion.
-    /// Note that immediate deletion of some resources may result in inconsistency
-    /// or data loss and requires confirmation.
-    #[arg(long)]
-    force: bool,
+    #[command(flatten)]
+    grace_period: GracePeriod,
 
     /// Treat "resource not found" as a successful delete. Defaults to "true" when --all is specified.
     #[arg(long, default_value_if("all", ArgPredicate::IsPresent, "true"))]
@@ -171,11 +175,25 @@ pub struct Delete {
     #[arg(long)]
     all: bool,
 
+    /// Ask for confirmation before deleting more than COUNT objects of a type at once, with --all or a selector.
+    #[arg(long, value_name = "COUNT", env = "RKUBECTL_CONFIRM_ABOVE")]
+    confirm_above: Option<usize>,
+
     /// If true, wait for resources to be gone before returning.
     /// This waits for finalizers.
-    #[arg(long, default_value_t = true)]
+    #[arg(
+        long,
+        default_value_t = true,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        action = ArgAction::Set,
+    )]
     wait: bool,
 
+    /// The length of time to wait before giving up on a delete, zero means wait forever.
+    #[arg(long, value_parser = Duration::value_parser(), default_value = "0s")]
+    timeout: Duration,
+
     #[arg(id = "TYPE", value_name = "TYPE", required_unless_present("filename"))]
     resources: Option<Vec<String>>,
 }
@@ -183,42 +201,291 @@ pub struct Delete {
 impl Delete {
     pub async fn exec(self, context: &Context) -> RkResult<()> {
         let kubeapi = context.kubeapi();
-        if let Some(filename) = &self.filename {
-            let dp = api::DeleteParams::default();
-            todo!("Deleting from {filename} ({dp:?}");
+        self.grace_period.check()?;
+        if !self.filename.is_empty() {
+            self.delete_from_files(kubeapi).await
         } else {
-            self.delete_resources(kubeapi).await?;
+            self.delete_resources(kubeapi).await
         }
-        Ok(())
     }
 
-    async fn delete_resources(&self, kubeapi: &Kubeapi) -> kube::Result<()> {
-        let dp = kubeapi.delete_params(self.cascade, self.dry_run);
-        for resource in self.resources(kubeapi)? {
+    async fn delete_from_files(&self, kubeapi: &Kubeapi) -> RkResult<()> {
+        let dp = self.delete_params(kubeapi);
+        for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+            let object = ManifestObject::new(kubeapi, manifest).await?;
+            let kind = object.kind();
             if self.dry_run == DryRun::Client {
-                println!("{resource} deleted (dry run)");
-            } else {
-                resource
-                    .delete(kubeapi, &dp, self.all)
+                self.deleted(&kind, &object.name());
+                continue;
+            }
+
+            let api = object.api(kubeapi)?;
+            let deleted = match api.delete(&object.name(), &dp).await {
+                Ok(deleted) => deleted,
+                Err(kube::Error::Api(status)) if self.ignore_not_found && status.is_not_found() => {
+                    continue;
+                }
+                Err(err) => return Err(object.error(err).into()),
+            };
+            self.deleted(&kind, &object.name());
+            if let Some(deleted) = deleted.left() {
+                self.wait_deleted(kubeapi, &api, &kind, &deleted)
                     .await
-                    .or_else(|err| self.ignore_not_found(err))?;
+                    .map_err(|err| match err {
+                        RkError::Kube(err) => object.error(err).into(),
+                        err => err,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete_resources(&self, kubeapi: &Kubeapi) -> RkResult<()> {
+        let dp = self.delete_params(kubeapi);
+        for resource in self.resources(kubeapi)? {
+            match resource {
+                ResourceArg::NamedResource(ref named) => {
+                    self.delete_named(kubeapi, named, &dp).await?;
+                }
+                ResourceArg::Resource(ref resource) => {
+                    self.delete_selected(kubeapi, resource, &dp).await?;
+                }
             }
         }
 
         Ok(())
     }
 
-    fn resources(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<ResourceArg>> {
+    async fn delete_named(
+        &self,
+        kubeapi: &Kubeapi,
+        resource: &NamedResource,
+        dp: &api::DeleteParams,
+    ) -> RkResult<()> {
+        let kind = resource.resource().qualified_kind();
+        if self.dry_run == DryRun::Client {
+            self.deleted(&kind, resource.name());
+            return Ok(());
+        }
+
+        let deleted = match resource.delete(kubeapi, dp).await {
+            Ok(deleted) => deleted,
+            Err(kube::Error::Api(status)) if self.ignore_not_found && status.is_not_found() => {
+                return Ok(());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        self.deleted(&kind, resource.name());
+        if let Some(deleted) = deleted {
+            let (scope, ref dyntype) = resource.resource().api_resource();
+            let api = kubeapi.object_api(scope, dyntype, deleted.namespace().as_deref())?;
+            self.wait_deleted(kubeapi, &api, &kind, &deleted).await?;
+        }
+        Ok(())
+    }
+
+    /// Deletes the objects of a resource type picked by --all or the label selector
+    async fn delete_selected(
+        &self,
+        kubeapi: &Kubeapi,
+        resource: &Resource,
+        dp: &api::DeleteParams,
+    ) -> RkResult<()> {
+        let kind = resource.qualified_kind();
+        let objects = resource.selected(kubeapi, self.all).await?;
+        if objects.is_empty() {
+            println!("No resources found");
+            return Ok(());
+        }
+        let confirmed = match self.confirm(&kind, &objects)? {
+            Some(true) => true,
+            Some(false) => {
+                println!("deletion is cancelled");
+                return Ok(());
+            }
+            None => false,
+        };
+
+        if self.dry_run == DryRun::Client {
+            objects
+                .iter()
+                .for_each(|object| self.deleted(&kind, &object.name_any()));
+            return Ok(());
+        }
+        // Only what the user confirmed, rather than whatever matches the selector by now
+        let deleted = if confirmed {
+            resource.delete_objects(kubeapi, dp, &objects).await?
+        } else {
+            resource.delete_selected(kubeapi, dp, &objects).await?
+        };
+        deleted
+            .iter()
+            .for_each(|object| self.deleted(&kind, &object.name_any()));
+
+        let (scope, ref dyntype) = resource.api_resource();
+        for object in &deleted {
+            let api = kubeapi.object_api(scope.clone(), dyntype, object.namespace().as_deref())?;
+            self.wait_deleted(kubeapi, &api, &kind, object).await?;
+        }
+        Ok(())
+    }
+
+    /// With --confirm-above, asks before deleting more than that many objects at once.
+    /// Returns whether the user agreed, or `None` when not asked.
+    fn confirm(&self, kind: &str, objects: &[api::DynamicObject]) -> RkResult<Option<bool>> {
+        match self.confirm_above {
+            Some(limit) if objects.len() > limit => {}
+            Some(_) | None => return Ok(None),
+        }
+
+        println!(
+            "You are about to delete the following {} resource(s):",
+            objects.len()
+        );
+        for object in objects {
+            match object.namespace() {
+                Some(namespace) => println!("{kind} \"{}\" in {namespace}", object.name_any()),
+                None => println!("{kind} \"{}\"", object.name_any()),
+            }
+        }
+        print!("Do you want to continue? (y/n): ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        Ok(Some(answer.trim().eq_ignore_ascii_case("y")))
+    }
+
+    fn resources(&self, kubeapi: &Kubeapi) -> RkResult<Vec<ResourceArg>> {
         let resources = self.resources.as_deref().unwrap_or_default();
-        ResourceArg::from_strings(resources, kubeapi)
-            .map_err(|_err| kube::Error::LinesCodecMaxLineLengthExceeded)
+        ResourceArg::from_strings(resources, kubeapi).map_err(RkError::invalid_arguments)
+    }
+
+    fn delete_params(&self, kubeapi: &Kubeapi) -> api::DeleteParams {
+        self.grace_period
+            .delete_params(kubeapi, self.cascade, self.dry_run)
+    }
+
+    /// Same as kubectl, e.g. `pod "web" deleted`
+    fn deleted(&self, kind: &str, name: &str) {
+        println!("{kind} \"{name}\" deleted{}", self.dry_run.suffix());
     }
 
-    fn ignore_not_found(&self, err: kube::Error) -> kube::Result<()> {
-        if self.ignore_not_found && matches!(err, kube::Error::Api(ref err) if err.is_not_found()) {
+    /// With --wait, blocks until `deleted`, which the server still holds, is gone
+    async fn wait_deleted(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        kind: &str,
+        deleted: &api::DynamicObject,
+    ) -> RkResult<()> {
+        if !self.wait || self.dry_run != DryRun::None {
+            return Ok(());
+        }
+        let name = deleted.name_any();
+        let uid = deleted.uid();
+        let timeout = (!self.timeout.is_zero()).then(|| self.timeout.duration());
+        if kubeapi
+            .wait_deleted(api, &name, uid.as_deref(), timeout)
+            .await?
+        {
             Ok(())
         } else {
-            Err(err)
+            Err(RkError::Timeout(format!(
+                "timed out waiting for the deletion of {kind} \"{name}\""
+            )))
+        }
+    }
+}
+
+/// The --force, --grace-period and --now flags, shared with `replace --force`
+#[derive(Clone, Copy, Debug, Args)]
+pub(crate) struct GracePeriod {
+    /// If true, immediately remove resources from API and bypass graceful deletion.
+    /// Note that immediate deletion of some resources may result in inconsistency
+    /// or data loss and requires confirmation.
+    #[arg(long)]
+    force: bool,
+
+    /// Period of time in seconds given to the resource to terminate gracefully. Ignored if negative.
+    /// Set to 1 for immediate shutdown. Can only be set to 0 when --force is true (force deletion).
+    #[arg(long, default_value_t = -1, allow_negative_numbers = true)]
+    grace_period: i64,
+
+    /// If true, resources are signaled for immediate shutdown (same as --grace-period=1).
+    #[arg(long, conflicts_with = "grace_period")]
+    now: bool,
+}
+
+impl GracePeriod {
+    pub(crate) fn force(self) -> bool {
+        self.force
+    }
+
+    /// Whether --grace-period or --now was given
+    pub(crate) fn is_set(self) -> bool {
+        self.now || self.grace_period >= 0
+    }
+
+    /// Rejects --grace-period=0 without --force, and warns about an immediate deletion
+    pub(crate) fn check(self) -> RkResult<()> {
+        if self.force && self.grace_period < 0 {
+            eprintln!(
+                "Warning: Immediate deletion does not wait for confirmation that the running resource has been terminated. The resource may continue to run on the cluster indefinitely."
+            );
+        }
+        if self.grace_period == 0 && !self.force {
+            return Err(RkError::invalid_arguments(
+                "--grace-period must have --force specified",
+            ));
         }
+        Ok(())
+    }
+
+    pub(crate) fn delete_params(
+        self,
+        kubeapi: &Kubeapi,
+        cascade: Cascade,
+        dry_run: DryRun,
+    ) -> api::DeleteParams {
+        let grace_period = match (self.now, self.force) {
+            (true, _) => 1,
+            (false, true) if self.grace_period < 0 => 0,
+            (false, _) => self.grace_period,
+        };
+        api::DeleteParams {
+            grace_period_seconds: u32::try_from(grace_period).ok(),
+            ..kubeapi.delete_params(cascade, dry_run)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        delete: Delete,
+    }
+
+    #[test]
+    fn grace_period() {
+        let kubeapi = Kubeapi::local();
+        let grace_period = |args: &[&str]| {
+            let args = ["delete", "pod/web"].iter().chain(args);
+            Command::parse_from(args)
+                .delete
+                .delete_params(&kubeapi)
+                .grace_period_seconds
+        };
+
+        assert_eq!(grace_period(&[]), None);
+        assert_eq!(grace_period(&["--now"]), Some(1));
+        assert_eq!(grace_period(&["--force"]), Some(0));
+        assert_eq!(grace_period(&["--force", "--grace-period=5"]), Some(5));
+        assert_eq!(grace_period(&["--grace-period=10"]), Some(10));
     }
 }
@@ -0,0 +1,385 @@
+use std::env;
+use std::io::Write;
+use std::process;
+
+use rkubectl_resource::ManifestObject;
+use rkubectl_resource::MergeKeys;
+use rkubectl_resource::PatchStrategy;
+use rkubectl_resource::patch_strategic;
+use rkubectl_resource::three_way_patch;
+
+use super::*;
+
+/// Edit a resource from the default editor.
+///
+///  The edit command allows you to directly edit any API resource you can retrieve via the command-line tools. It will
+/// open the editor defined by your KUBE_EDITOR, or EDITOR environment variables, or fall back to 'vi'. The editor
+/// command is split on whitespace, and the file to edit is passed as its last argument.
+///
+///  You can edit multiple objects, although changes are applied one at a time. The command accepts file names as well
+/// as command-line arguments, although the files you point to must be previously saved versions of resources.
+///
+///  Editing is done with the API version used to fetch the resource. The default format is YAML. To edit in JSON,
+/// specify "-o json".
+///
+///  In the event an error occurs while updating, the file is reopened with the error embedded as comments. Saving it
+/// unchanged gives up, and a temporary file containing your unapplied changes is left behind.
+///
+/// Examples:
+///   # Edit the service named 'registry'
+///   kubectl edit svc/registry
+///
+///   # Use an alternative editor
+///   KUBE_EDITOR="nano" kubectl edit svc/registry
+///
+///   # Edit the job 'myjob' in JSON using the v1 API format
+///   kubectl edit job.v1.batch/myjob -o json
+///
+///   # Edit the deployment 'mydeployment' in YAML and save the modified config in its annotation
+///   kubectl edit deployment/mydeployment -o yaml --save-config
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Edit {
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-edit")]
+    field_manager: String,
+
+    /// Filename, directory, or URL to files to use to edit the resource
+    #[arg(short, long)]
+    filename: Vec<String>,
+
+    /// Output the patch if the resource is edited.
+    #[arg(long)]
+    output_patch: bool,
+
+    /// Process the directory used in -f, --filename recursively.
+    /// Useful when you want to manage related manifests organized within the same directory.
+    #[arg(long, short = 'R', requires = "filename")]
+    recursive: bool,
+
+    /// If true, the configuration of current object will be saved in its annotation.
+    /// Otherwise, the annotation will be unchanged.
+    /// This flag is useful when you want to perform kubectl apply on this object in the future.
+    #[arg(long)]
+    save_config: bool,
+
+    #[command(flatten)]
+    params: ShowParams,
+
+    #[arg(
+        value_name = "TYPE NAME | TYPE/NAME",
+        required_unless_present = "filename"
+    )]
+    resources: Vec<String>,
+}
+
+impl Edit {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let namespace = kubeapi.show_namespace();
+        let editor = Editor::new(output, self.params);
+
+        for (api, mut object) in self.objects(kubeapi).await? {
+            if !self.params.show_managed_fields {
+                object.metadata.managed_fields = None;
+            }
+            // What the editor starts from, so that hidden fields are not dropped by the patch
+            let original = json::to_value(&object).map_err(kube::Error::SerdeError)?;
+            let types = object.types.clone().unwrap_or_default();
+            let keys = MergeKeys::new(kubeapi, &types.api_version, &types.kind).await;
+            let save = async |edited| self.save(kubeapi, &api, &keys, &original, edited).await;
+            let Some((edited, operation)) = editor.edit(&object, save).await? else {
+                println!("Edit cancelled, no changes made.");
+                continue;
+            };
+            match output {
+                OutputFormat::Normal | OutputFormat::Wide => {
+                    let name = edited.output(namespace, self.params, OutputFormat::Name);
+                    println!("{name} {operation}");
+                }
+                output => println!("{}", edited.output(namespace, self.params, output)),
+            }
+        }
+        Ok(())
+    }
+
+    /// The live objects to edit, with the API to update them through
+    async fn objects(
+        &self,
+        kubeapi: &Kubeapi,
+    ) -> RkResult<Vec<(api::Api<api::DynamicObject>, api::DynamicObject)>> {
+        let mut objects = vec![];
+        if !self.filename.is_empty() {
+            for manifest in rkubectl_manifest::load(&self.filename, self.recursive).await? {
+                let object = ManifestObject::new(kubeapi, manifest).await?;
+                let api = object.api(kubeapi)?;
+                let live = api
+                    .get(&object.name())
+                    .await
+                    .map_err(|err| object.error(err))?;
+                objects.push((api, live));
+            }
+        } else {
+            let resources = ResourceArg::from_strings(&self.resources, kubeapi)
+                .map_err(RkError::invalid_arguments)?;
+            for resource in resources {
+                let (scope, ref dyntype) = resource.resource().api_resource();
+                for object in resource.dynamic_objects(kubeapi).await? {
+                    let namespace = object.namespace();
+                    let api = kubeapi.object_api(scope.clone(), dyntype, namespace.as_deref())?;
+                    objects.push((api, object));
+                }
+            }
+        }
+        Ok(objects)
+    }
+
+    /// Patches the live object with the changes from `original` to `edited`
+    async fn save(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        keys: &MergeKeys,
+        original: &json::Value,
+        mut edited: api::DynamicObject,
+    ) -> RkResult<(api::DynamicObject, &'static str)> {
+        let name = original["metadata"]["name"].as_str().unwrap_or_default();
+        let unchanged = edited.metadata.name.as_deref() == Some(name)
+            && edited.types.as_ref().is_some_and(|types| {
+                original["apiVersion"] == types.api_version.as_str()
+                    && original["kind"] == types.kind.as_str()
+            });
+        if !unchanged {
+            return Err(RkError::invalid_arguments(
+                "At least one of apiVersion, kind and name was changed",
+            ));
+        }
+        if self.save_config {
+            rkubectl_resource::save_config(&mut edited).map_err(kube::Error::SerdeError)?;
+        }
+
+        let modified = json::to_value(&edited).map_err(kube::Error::SerdeError)?;
+        let patch = |strategy| edit_patch(original, &modified, strategy);
+        let strategic = patch(PatchStrategy::Strategic(keys));
+        if strategic.as_object().is_some_and(json::Map::is_empty) {
+            let live = json::from_value(original.clone()).map_err(kube::Error::SerdeError)?;
+            return Ok((live, "skipped"));
+        }
+        if self.output_patch {
+            println!("Patch: {strategic}");
+        }
+
+        let pp = kubeapi.patch_params(&self.field_manager, DryRun::None);
+        let merge = || patch(PatchStrategy::Merge);
+        let edited = patch_strategic(api, name, &pp, strategic, merge).await?;
+        Ok((edited, "edited"))
+    }
+}
+
+/// The patch taking the live object the editor started from, `original`, to `edited`
+fn edit_patch(
+    original: &json::Value,
+    edited: &json::Value,
+    strategy: PatchStrategy<'_>,
+) -> json::Value {
+    three_way_patch(Some(original), edited, original, strategy)
+}
+
+/// Round trips objects through `$KUBE_EDITOR` or `$EDITOR`, for `edit` and `create --edit`
+pub(crate) struct Editor {
+    output: OutputFormat,
+    params: ShowParams,
+}
+
+impl Editor {
+    const HEADER: &str = "\
+# Please edit the object below. Lines beginning with a '#' will be ignored,
+# and an empty file will abort the edit. If an error occurs while saving this file will be
+# reopened with the relevant failures.
+#
+";
+
+    /// Edits in JSON with `-o json`, in YAML otherwise
+    pub(crate) fn new(output: OutputFormat, params: ShowParams) -> Self {
+        let output = match output {
+            OutputFormat::Json => OutputFormat::Json,
+            _ => OutputFormat::Yaml,
+        };
+        Self { output, params }
+    }
+
+    /// Opens `object` in the editor until `save` takes the edited object, reopening it with the error otherwise.
+    /// Returns `None` when the edit is cancelled by saving an empty file or the object unchanged.
+    pub(crate) async fn edit<T>(
+        &self,
+        object: &api::DynamicObject,
+        save: impl AsyncFn(api::DynamicObject) -> RkResult<T>,
+    ) -> RkResult<Option<T>> {
+        let name = object.output(false, self.params, OutputFormat::Name);
+        let original = object.output(false, self.params, self.output);
+        let mut text = original.clone();
+        let mut failed = None;
+        let mut error = String::new();
+
+        loop {
+            let edited = self.launch(&format!("{}{error}{text}", Self::HEADER))?;
+            let edited = strip_comments(&edited);
+            if edited.trim().is_empty() || edited.trim_end() == original.trim_end() {
+                return Ok(None);
+            }
+            if failed.as_ref() == Some(&edited) {
+                let path = self.keep(&edited)?;
+                return Err(RkError::Edit(format!(
+                    "Edit cancelled, no valid changes were saved.\nA copy of your changes has been stored to {}",
+                    path.display()
+                )));
+            }
+
+            let result = match parse(&edited) {
+                Ok(object) => save(object).await,
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(saved) => return Ok(Some(saved)),
+                Err(err) => {
+                    error = error_comment(&name, &err);
+                    text.clone_from(&edited);
+                    failed = Some(edited);
+                }
+            }
+        }
+    }
+
+    /// Writes `text` to a temporary file, runs the editor on it, and reads it back
+    fn launch(&self, text: &str) -> RkResult<String> {
+        let mut file = self.temp_file()?;
+        file.write_all(text.as_bytes())?;
+        file.flush()?;
+
+        let editor = ["KUBE_EDITOR", "EDITOR"]
+            .into_iter()
+            .find_map(|name| {
+                env::var(name)
+                    .ok()
+                    .filter(|editor| !editor.trim().is_empty())
+            })
+            .unwrap_or_else(|| "vi".to_string());
+        let mut words = editor.split_whitespace();
+        let program = words.next().unwrap_or("vi");
+        let status = process::Command::new(program)
+            .args(words)
+            .arg(file.path())
+            .status()?;
+        if !status.success() {
+            return Err(RkError::Edit(format!(
+                "there was a problem with the editor \"{editor}\""
+            )));
+        }
+        Ok(fs::read_to_string(file.path())?)
+    }
+
+    /// Leaves a copy of `text` behind, when giving up on the edit
+    fn keep(&self, text: &str) -> RkResult<PathBuf> {
+        let mut file = self.temp_file()?;
+        file.write_all(text.as_bytes())?;
+        let path = file.into_temp_path().keep().map_err(|err| err.error)?;
+        Ok(path)
+    }
+
+    fn temp_file(&self) -> io::Result<tempfile::NamedTempFile> {
+        let suffix = match self.output {
+            OutputFormat::Json => ".json",
+            _ => ".yaml",
+        };
+        tempfile::Builder::new()
+            .prefix("kubectl-edit-")
+            .suffix(suffix)
+            .tempfile()
+    }
+}
+
+/// Drops the lines starting with '#', as written by the editor header and errors
+fn strip_comments(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+fn parse(text: &str) -> RkResult<api::DynamicObject> {
+    let invalid = |err: &dyn std::error::Error| RkError::invalid_arguments(err.to_string());
+    let value = serde_yaml::from_str::<json::Value>(text).map_err(|err| invalid(&err))?;
+    json::from_value(value).map_err(|err| invalid(&err))
+}
+
+/// Same as kubectl, the error as a comment block for the reopened file
+fn error_comment(name: &str, err: &RkError) -> String {
+    let message = match err {
+        RkError::Kube(kube::Error::Api(status)) => status.message.clone(),
+        err => err.to_string(),
+    };
+    let mut lines = message.lines();
+    let first = lines.next().unwrap_or_default();
+    let rest = lines.map(|line| format!("# {line}\n")).collect::<String>();
+    format!("# {name} was not valid:\n# * {first}\n{rest}#\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reopened_file() {
+        let err = RkError::invalid_arguments("spec.replicas: Invalid value\nmust be positive");
+        let comment = error_comment("deployment.apps/web", &err);
+        assert_eq!(
+            comment,
+            "# deployment.apps/web was not valid:\n# * spec.replicas: Invalid value\n# must be positive\n#\n"
+        );
+
+        let text = format!(
+            "{}{comment}kind: Deployment\nspec:\n  # kept\n  replicas: 1\n",
+            Editor::HEADER
+        );
+        assert_eq!(
+            strip_comments(&text),
+            "kind: Deployment\nspec:\n  # kept\n  replicas: 1\n"
+        );
+    }
+
+    #[test]
+    fn removed_finalizer() {
+        let document = json::json!({"components": {"schemas": {
+            "io.k8s.api.core.v1.ConfigMap": {
+                "properties": {"metadata": {"$ref": "#/components/schemas/io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta"}},
+                "x-kubernetes-group-version-kind": [{"group": "", "kind": "ConfigMap", "version": "v1"}]
+            },
+            "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {"properties": {"finalizers": {
+                "items": {"type": "string"},
+                "type": "array",
+                "x-kubernetes-patch-strategy": "merge"
+            }}}
+        }}});
+        let keys = MergeKeys::from_document(document, "", "v1", "ConfigMap");
+        let strategy = PatchStrategy::Strategic(&keys);
+        let original = json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {"name": "stuck", "finalizers": ["example.com/a", "example.com/b"]},
+        });
+        let mut edited = original.clone();
+        edited["metadata"]["finalizers"] = json::json!(["example.com/a"]);
+
+        let patch = edit_patch(&original, &edited, strategy);
+        assert_eq!(
+            patch,
+            json::json!({"metadata": {"$deleteFromPrimitiveList/finalizers": ["example.com/b"]}})
+        );
+        let patched = rkubectl_resource::apply_patch(&original, &patch, strategy);
+        assert_eq!(
+            patched["metadata"]["finalizers"],
+            json::json!(["example.com/a"])
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use ext::APIResourceListExt;
+use rkubectl_resource::Resource;
+
+use schema::Schema;
+
+use super::*;
+
+mod schema;
+
+/// Describe fields and structure of various resources.
+///
+///  This command describes the fields associated with each supported API resource. Fields are identified via a simple
+/// JSONPath identifier:
+///
+///   <type>.<fieldName>[.<fieldName>]
+///
+///  Information about each field is retrieved from the server in OpenAPI v3 format, and cached next to the discovery
+/// cache. Custom resources are described by the schema of their CustomResourceDefinition.
+///
+///  Use "kubectl api-resources" for a complete list of supported resources.
+///
+/// Examples:
+///   # Get the documentation of the resource and its fields
+///   kubectl explain pods
+///
+///   # Get all the fields in the resource
+///   kubectl explain pods --recursive
+///
+///   # Get the explanation for deployment in supported api versions
+///   kubectl explain deployments --api-version=apps/v1
+///
+///   # Get the documentation of a specific field of a resource
+///   kubectl explain pods.spec.containers
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Explain {
+    /// Use given api-version (group/version) of the resource.
+    #[arg(long)]
+    api_version: Option<String>,
+
+    /// When true, display only field names and types, recursing into the fields of fields.
+    #[arg(long)]
+    recursive: bool,
+
+    #[arg(value_name = "TYPE[.FIELD.PATH]")]
+    resource: String,
+}
+
+impl Explain {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let (resource, path) = self
+            .resource
+            .split_once('.')
+            .map_or((self.resource.as_str(), None), |(resource, path)| {
+                (resource, Some(path))
+            });
+        let dyntype = self.api_resource(kubeapi, resource)?;
+
+        let document = kubeapi
+            .openapi_v3(&dyntype.api_version)
+            .await?
+            .ok_or_else(|| {
+                RkError::not_found(format!(
+                    "couldn't find the OpenAPI schema for \"{}\"",
+                    dyntype.api_version
+                ))
+            })?;
+        let mut schema =
+            Schema::for_kind(&document, &dyntype.group, &dyntype.version, &dyntype.kind)
+                .ok_or_else(|| {
+                    RkError::not_found(format!(
+                        "couldn't find the schema of {} in {}",
+                        dyntype.kind, dyntype.api_version
+                    ))
+                })?;
+        let mut field = None;
+        for name in path.into_iter().flat_map(|path| path.split('.')) {
+            schema = schema
+                .field(name)
+                .ok_or_else(|| RkError::not_found(format!("field \"{name}\" does not exist")))?;
+            field = Some(name);
+        }
+
+        if !dyntype.group.is_empty() {
+            println!("GROUP:      {}", dyntype.group);
+        }
+        println!("KIND:       {}", dyntype.kind);
+        println!("VERSION:    {}", dyntype.version);
+        println!();
+        if let Some(field) = field {
+            println!("FIELD: {field} <{}>", schema.type_name());
+            println!();
+        }
+        println!("DESCRIPTION:");
+        print!("{}", schema::wrap(schema.description(), 4));
+        let fields = schema.render_fields(self.recursive);
+        if !fields.is_empty() {
+            println!();
+            println!("FIELDS:");
+            print!("{fields}");
+        }
+        Ok(())
+    }
+
+    fn api_resource(&self, kubeapi: &Kubeapi, resource: &str) -> RkResult<api::ApiResource> {
+        let dyntype = match &self.api_version {
+            Some(api_version) => kubeapi
+                .cached_server_api_resources()
+                .into_iter()
+                .filter(|arl| arl.group_version == *api_version)
+                .find_map(|arl| arl.kube_api_resource(resource))
+                .map(|(_scope, dyntype)| dyntype),
+            None => Resource::with_cache(resource, kubeapi)
+                .map(|resource| resource.api_resource())
+                .map(|(_scope, dyntype)| dyntype),
+        };
+        dyntype.ok_or_else(|| {
+            RkError::not_found(format!(
+                "the server doesn't have a resource type \"{resource}\""
+            ))
+        })
+    }
+}
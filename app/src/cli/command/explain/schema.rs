@@ -0,0 +1,336 @@
+use super::*;
+
+/// A field's schema within an OpenAPI v3 document, with `$ref`s followed
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Schema<'a> {
+    document: &'a json::Value,
+    /// The schema as written where it is used, carrying the field description
+    field: &'a json::Value,
+    /// The schema `field` refers to, or `field` itself
+    resolved: &'a json::Value,
+    /// The name of the resolved schema under `components.schemas`, if any
+    reference: Option<&'a str>,
+}
+
+impl<'a> Schema<'a> {
+    const WIDTH: usize = 80;
+
+    /// The schema of `kind`, going by the `x-kubernetes-group-version-kind` extension
+    pub(super) fn for_kind(
+        document: &'a json::Value,
+        group: &str,
+        version: &str,
+        kind: &str,
+    ) -> Option<Self> {
+        let is_kind = |gvk: &json::Value| {
+            gvk["group"] == group && gvk["version"] == version && gvk["kind"] == kind
+        };
+        document["components"]["schemas"]
+            .as_object()?
+            .iter()
+            .find(|(_, schema)| {
+                schema["x-kubernetes-group-version-kind"]
+                    .as_array()
+                    .is_some_and(|gvks| gvks.iter().any(is_kind))
+            })
+            .map(|(name, schema)| Self {
+                document,
+                field: schema,
+                resolved: schema,
+                reference: Some(name),
+            })
+    }
+
+    /// The schema of the `name` field, looking through lists and maps
+    pub(super) fn field(&self, name: &str) -> Option<Self> {
+        self.element()
+            .resolved
+            .get("properties")?
+            .get(name)
+            .map(|field| self.resolve(field))
+    }
+
+    /// The fields, with whether they are required, for objects and lists or maps of objects
+    pub(super) fn fields(&self) -> Vec<(&'a str, Self, bool)> {
+        let element = self.element();
+        let required = element.resolved["required"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(json::Value::as_str)
+            .collect::<Vec<_>>();
+        element.resolved["properties"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, field)| {
+                let field = self.resolve(field);
+                (name.as_str(), field, required.contains(&name.as_str()))
+            })
+            .collect()
+    }
+
+    pub(super) fn description(&self) -> &'a str {
+        self.field["description"]
+            .as_str()
+            .or_else(|| self.resolved["description"].as_str())
+            .unwrap_or_default()
+    }
+
+    /// Same as kubectl, e.g. `string`, `[]Container`, `map[string]string` or `ObjectMeta`
+    pub(super) fn type_name(&self) -> String {
+        if let Some(reference) = self.reference {
+            return reference
+                .rsplit('.')
+                .next()
+                .unwrap_or(reference)
+                .to_string();
+        }
+        match self.resolved["type"].as_str() {
+            Some("array") => match self.resolved.get("items") {
+                Some(items) => format!("[]{}", self.resolve(items).type_name()),
+                None => "[]Object".to_string(),
+            },
+            Some("object") => match self.resolved.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("map[string]{}", self.resolve(values).type_name())
+                }
+                _ => "Object".to_string(),
+            },
+            Some(type_) => type_.to_string(),
+            None if self.resolved.get("x-kubernetes-int-or-string").is_some() => {
+                "IntOrString".to_string()
+            }
+            None => "Object".to_string(),
+        }
+    }
+
+    /// The `FIELDS:` lines, with descriptions, or as a tree of names and types with `recursive`
+    pub(super) fn render_fields(&self, recursive: bool) -> String {
+        let mut text = String::new();
+        if recursive {
+            self.render_tree(&mut text, 1, &mut vec![self.reference]);
+        } else {
+            for (name, field, required) in self.fields() {
+                text.push_str(&field_line(name, &field, required, 1));
+                text.push_str(&wrap(field.description(), 4));
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    fn render_tree(&self, text: &mut String, depth: usize, seen: &mut Vec<Option<&'a str>>) {
+        for (name, field, required) in self.fields() {
+            text.push_str(&field_line(name, &field, required, depth));
+            // Schemas such as JSONSchemaProps refer to themselves
+            let element = field.element();
+            if element.reference.is_none() || !seen.contains(&element.reference) {
+                seen.push(element.reference);
+                field.render_tree(text, depth + 1, seen);
+                seen.pop();
+            }
+        }
+    }
+
+    /// The schema of the items of lists and the values of maps, or this one
+    fn element(&self) -> Self {
+        let nested = match self.resolved["type"].as_str() {
+            Some("array") => self.resolved.get("items"),
+            Some("object") if self.resolved.get("properties").is_none() => self
+                .resolved
+                .get("additionalProperties")
+                .filter(|values| values.is_object()),
+            _ => None,
+        };
+        nested.map_or(*self, |nested| self.resolve(nested).element())
+    }
+
+    /// Follows `$ref`, also when wrapped in a single `allOf` as done for fields with defaults
+    fn resolve(&self, field: &'a json::Value) -> Self {
+        let mut resolved = field;
+        let mut reference = None;
+        loop {
+            let target = resolved["$ref"].as_str().or_else(|| {
+                match resolved["allOf"].as_array().map(Vec::as_slice) {
+                    Some([single]) => single["$ref"].as_str(),
+                    _ => None,
+                }
+            });
+            let Some(name) = target.and_then(|target| target.strip_prefix("#/components/schemas/"))
+            else {
+                break;
+            };
+            match self.document["components"]["schemas"].get(name) {
+                Some(schema) if reference != Some(name) => {
+                    resolved = schema;
+                    reference = Some(name);
+                }
+                _ => break,
+            }
+        }
+        Self {
+            document: self.document,
+            field,
+            resolved,
+            reference,
+        }
+    }
+}
+
+fn field_line(name: &str, field: &Schema<'_>, required: bool, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let required = if required { " -required-" } else { "" };
+    format!("{indent}{name}\t<{}>{required}\n", field.type_name())
+}
+
+/// `text` wrapped to 80 columns, every line indented by `indent` spaces
+pub(super) fn wrap(text: &str, indent: usize) -> String {
+    let width = Schema::WIDTH.saturating_sub(indent).max(20);
+    let indent = " ".repeat(indent);
+    if text.trim().is_empty() {
+        return format!("{indent}<empty>\n");
+    }
+    let mut wrapped = String::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.len() + 1 + word.len() > width {
+                wrapped.push_str(&format!("{indent}{line}\n"));
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if line.is_empty() {
+            wrapped.push('\n');
+        } else {
+            wrapped.push_str(&format!("{indent}{line}\n"));
+        }
+    }
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document() -> json::Value {
+        json::json!({
+            "components": {
+                "schemas": {
+                    "io.k8s.api.apps.v1.Deployment": {
+                        "description": "Deployment enables declarative updates for Pods and ReplicaSets.",
+                        "properties": {
+                            "kind": {"type": "string", "description": "Kind is a string value."},
+                            "spec": {
+                                "allOf": [{"$ref": "#/components/schemas/io.k8s.api.apps.v1.DeploymentSpec"}],
+                                "default": {},
+                                "description": "Specification of the desired behavior of the Deployment."
+                            }
+                        },
+                        "type": "object",
+                        "x-kubernetes-group-version-kind": [
+                            {"group": "apps", "kind": "Deployment", "version": "v1"}
+                        ]
+                    },
+                    "io.k8s.api.apps.v1.DeploymentSpec": {
+                        "description": "DeploymentSpec is the specification of the desired behavior of the Deployment.",
+                        "properties": {
+                            "replicas": {"type": "integer", "format": "int32", "description": "Number of desired pods."},
+                            "selector": {"$ref": "#/components/schemas/io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector"},
+                            "containers": {
+                                "type": "array",
+                                "items": {"$ref": "#/components/schemas/io.k8s.api.apps.v1.DeploymentSpec"}
+                            }
+                        },
+                        "required": ["selector"],
+                        "type": "object"
+                    },
+                    "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector": {
+                        "description": "A label selector.",
+                        "properties": {
+                            "matchLabels": {
+                                "type": "object",
+                                "additionalProperties": {"type": "string", "default": ""}
+                            }
+                        },
+                        "type": "object"
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn fields() {
+        let document = document();
+        let deployment = Schema::for_kind(&document, "apps", "v1", "Deployment").unwrap();
+        let spec = deployment.field("spec").unwrap();
+        assert_eq!(spec.type_name(), "DeploymentSpec");
+        assert_eq!(
+            spec.description(),
+            "Specification of the desired behavior of the Deployment."
+        );
+
+        let fields = spec
+            .fields()
+            .into_iter()
+            .map(|(name, field, required)| (name, field.type_name(), required))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("containers", "[]DeploymentSpec".to_string(), false),
+                ("replicas", "integer".to_string(), false),
+                ("selector", "LabelSelector".to_string(), true),
+            ]
+        );
+
+        let labels = spec
+            .field("selector")
+            .unwrap()
+            .field("matchLabels")
+            .unwrap();
+        assert_eq!(labels.type_name(), "map[string]string");
+        // Lists are looked through
+        assert!(
+            spec.field("containers")
+                .unwrap()
+                .field("replicas")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        let document = document();
+        let deployment = Schema::for_kind(&document, "apps", "v1", "Deployment").unwrap();
+        assert_eq!(
+            deployment.render_fields(true),
+            "  kind\t<string>
+  spec\t<DeploymentSpec>
+    containers\t<[]DeploymentSpec>
+    replicas\t<integer>
+    selector\t<LabelSelector> -required-
+      matchLabels\t<map[string]string>
+"
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let text =
+            "one two three four five six seven eight nine ten eleven twelve thirteen fourteen";
+        let wrapped = wrap(text, 60);
+        assert!(wrapped.lines().all(|line| line.len() <= 80), "{wrapped}");
+        assert!(
+            wrapped
+                .lines()
+                .all(|line| line.starts_with(&" ".repeat(60)))
+        );
+        assert_eq!(wrap("", 4), "    <empty>\n");
+    }
+}
@@ -1,3 +1,6 @@
+use futures_util::StreamExt;
+use rkubectl_ui::RowPrinter;
+
 use super::*;
 
 /// Display one or many resources
@@ -21,6 +24,23 @@ pub struct Get {
     #[arg(long, conflicts_with = "regular")]
     raw: Option<String>,
 
+    /// If true, have the server return the appropriate table output.
+    /// Supports extension APIs and CRDs.
+    #[arg(long)]
+    server_print: bool,
+
+    /// After listing/getting the requested object, watch for changes.
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Watch for changes to the requested object(s), without listing/getting first.
+    #[arg(long)]
+    watch_only: bool,
+
+    /// Indicates that watch events should be output (ADDED, MODIFIED, DELETED).
+    #[arg(long)]
+    output_watch_events: bool,
+
     /// If specified, gets the subresource of the requested object.
     #[arg(group = "regular", long)]
     subresource: Option<String>,
@@ -31,6 +51,8 @@ pub struct Get {
 }
 
 impl Get {
+    const WATCH_BATCH: usize = 256;
+
     pub async fn exec(&self, context: &Context) -> RkResult<()> {
         let kubeapi = context.kubeapi();
         if let Some(raw) = self.raw.as_deref() {
@@ -42,15 +64,50 @@ impl Get {
             let mut params = self.params;
             params.show_kind |= resources.len() > 1;
             let namespace = kubeapi.show_namespace();
+            let output = context.output_deprecated();
+            if self.watch || self.watch_only {
+                return self.watch(kubeapi, &resources, params, output).await;
+            }
+            let server_print =
+                self.server_print && matches!(output, OutputFormat::Normal | OutputFormat::Wide);
             for resource in resources {
-                let data = resource.get(kubeapi).await?;
-                let output = context.output_deprecated();
+                let data = if server_print {
+                    resource.get_table(kubeapi).await?
+                } else {
+                    resource.get(kubeapi).await?
+                };
                 println!("{}", data.output(namespace, params, output));
             }
         }
         Ok(())
     }
 
+    async fn watch(
+        &self,
+        kubeapi: &Kubeapi,
+        resources: &[ResourceArg],
+        params: ShowParams,
+        output: OutputFormat,
+    ) -> RkResult<()> {
+        let [resource] = resources else {
+            return Err(RkError::invalid_arguments(
+                "watch is only supported on individual resources and resource collections",
+            ));
+        };
+        let namespace = kubeapi.show_namespace();
+        let mut printer = RowPrinter::default();
+        let mut events = resource
+            .watch(kubeapi, self.watch_only, self.output_watch_events)
+            .await?
+            // Whatever is ready at once (e.g. the initial list) is printed as a single batch
+            .ready_chunks(Self::WATCH_BATCH);
+        while let Some(batch) = events.next().await {
+            let batch = batch.into_iter().collect::<kube::Result<Vec<_>>>()?;
+            println!("{}", printer.batch(&batch, namespace, params, output));
+        }
+        Ok(())
+    }
+
     fn resources(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<ResourceArg>> {
         let resources = self.resources.as_deref().unwrap_or_default();
         ResourceArg::from_strings(resources, kubeapi)
@@ -0,0 +1,55 @@
+use super::*;
+
+pub use history::RolloutHistory;
+pub use pause::RolloutPause;
+pub use pause::RolloutResume;
+pub use restart::RolloutRestart;
+pub use status::RolloutStatus;
+pub use undo::RolloutUndo;
+
+use workload::Kind;
+use workload::Revision;
+use workload::Workload;
+
+mod history;
+mod pause;
+mod restart;
+mod status;
+mod undo;
+mod workload;
+
+/// Manage the rollout of one or many resources.
+///
+///  Valid resource types include:
+///
+///   *  deployments
+///   *  daemonsets
+///   *  statefulsets
+#[derive(Clone, Debug, Subcommand)]
+pub enum Rollout {
+    /// View rollout history
+    History(RolloutHistory),
+    /// Mark the provided resource as paused
+    Pause(RolloutPause),
+    /// Restart a resource
+    Restart(RolloutRestart),
+    /// Resume a paused resource
+    Resume(RolloutResume),
+    /// Show the status of the rollout
+    Status(RolloutStatus),
+    /// Undo a previous rollout
+    Undo(RolloutUndo),
+}
+
+impl Rollout {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        match self {
+            Self::History(history) => history.exec(context).await,
+            Self::Pause(pause) => pause.exec(context).await,
+            Self::Restart(restart) => restart.exec(context).await,
+            Self::Resume(resume) => resume.exec(context).await,
+            Self::Status(status) => status.exec(context).await,
+            Self::Undo(undo) => undo.exec(context).await,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+/// View previous rollout revisions and configurations.
+///
+/// Examples:
+///   # View the rollout history of a deployment
+///   kubectl rollout history deployment/abc
+///
+///   # View the details of daemonset revision 3
+///   kubectl rollout history daemonset/abc --revision=3
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutHistory {
+    /// See the details, including podTemplate of the revision specified
+    #[arg(long, default_value_t = 0)]
+    revision: i64,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl RolloutHistory {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        for workload in Workload::from_args(&self.resources, kubeapi)? {
+            let revisions = workload.revisions(kubeapi).await?;
+            if self.revision > 0 {
+                let revision = revisions
+                    .iter()
+                    .find(|revision| revision.number == self.revision)
+                    .ok_or_else(|| {
+                        RkError::not_found(format!(
+                            "unable to find the specified revision {} for {workload}",
+                            self.revision
+                        ))
+                    })?;
+                println!("{workload} with revision #{}", revision.number);
+                let data = serde_yaml::to_string(&revision.data)
+                    .map_err(|err| RkError::invalid_arguments(err.to_string()))?;
+                print!("{data}");
+            } else {
+                println!("{workload}");
+                print!("{}", table(&revisions));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Same as kubectl, a `REVISION  CHANGE-CAUSE` table
+fn table(revisions: &[Revision]) -> String {
+    let mut table = "REVISION  CHANGE-CAUSE\n".to_string();
+    for revision in revisions {
+        let change_cause = revision.change_cause.as_deref().unwrap_or("<none>");
+        table.push_str(&format!("{:<10}{change_cause}\n", revision.number));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_causes() {
+        let revisions = [
+            Revision {
+                number: 1,
+                change_cause: None,
+                data: json::Value::Null,
+            },
+            Revision {
+                number: 2,
+                change_cause: Some("kubectl set image deployment/web web=nginx:1.27".to_string()),
+                data: json::Value::Null,
+            },
+        ];
+        assert_eq!(
+            table(&revisions),
+            "REVISION  CHANGE-CAUSE\n1         <none>\n2         kubectl set image deployment/web web=nginx:1.27\n"
+        );
+    }
+}
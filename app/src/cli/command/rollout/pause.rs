@@ -0,0 +1,85 @@
+use super::*;
+
+/// Mark the provided resource as paused.
+///
+///  Paused resources will not be reconciled by a controller. Use "kubectl rollout resume" to resume a paused resource.
+/// Currently only deployments support being paused.
+///
+/// Examples:
+///   # Mark the nginx deployment as paused
+///   # Any current state of the deployment will continue its function; new updates
+///   # to the deployment will not have an effect as long as the deployment is paused
+///   kubectl rollout pause deployment/nginx
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutPause {
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-rollout")]
+    field_manager: String,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+/// Resume a paused resource.
+///
+///  Paused resources will not be reconciled by a controller. By resuming a resource, we allow it to be reconciled
+/// again. Currently only deployments support being resumed.
+///
+/// Examples:
+///   # Resume an already paused deployment
+///   kubectl rollout resume deployment/nginx
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutResume {
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-rollout")]
+    field_manager: String,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl RolloutPause {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        set_paused(context, &self.resources, &self.field_manager, true).await
+    }
+}
+
+impl RolloutResume {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        set_paused(context, &self.resources, &self.field_manager, false).await
+    }
+}
+
+async fn set_paused(
+    context: &Context,
+    resources: &[String],
+    field_manager: &str,
+    paused: bool,
+) -> RkResult<()> {
+    let kubeapi = context.kubeapi();
+    let (verb, already) = if paused {
+        ("paused", "is already paused")
+    } else {
+        ("resumed", "is not paused")
+    };
+    for workload in Workload::from_args(resources, kubeapi)? {
+        let deployment = workload.deployment(kubeapi).await?;
+        if deployment
+            .spec
+            .and_then(|spec| spec.paused)
+            .unwrap_or_default()
+            == paused
+        {
+            return Err(RkError::invalid_arguments(format!("{workload} {already}")));
+        }
+        let pp = kubeapi.patch_params(field_manager, DryRun::None);
+        let patch = json::json!({"spec": {"paused": paused}});
+        workload
+            .patch(kubeapi, &pp, &api::Patch::Strategic(patch))
+            .await?;
+        println!("{workload} {verb}");
+    }
+    Ok(())
+}
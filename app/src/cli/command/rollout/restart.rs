@@ -0,0 +1,65 @@
+use super::*;
+
+/// Restart a resource.
+///
+///  Resource rollout will be restarted.
+///
+/// Examples:
+///   # Restart a deployment
+///   kubectl rollout restart deployment/nginx
+///
+///   # Restart a daemon set
+///   kubectl rollout restart daemonset/abc
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutRestart {
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// Name of the manager used to track field ownership.
+    #[arg(long, default_value = "kubectl-rollout")]
+    field_manager: String,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl RolloutRestart {
+    const RESTARTED_AT: &str = "kubectl.kubernetes.io/restartedAt";
+
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        for workload in Workload::from_args(&self.resources, kubeapi)? {
+            if workload.kind == Kind::Deployment {
+                let deployment = workload.deployment(kubeapi).await?;
+                if deployment.spec.and_then(|spec| spec.paused) == Some(true) {
+                    return Err(RkError::invalid_arguments(format!(
+                        "can't restart paused deployment (run rollout resume first): {workload}"
+                    )));
+                }
+            }
+
+            // Changing the pod template is what makes the controller roll out new pods
+            let now = k8s::openapi::jiff::Timestamp::now().strftime("%Y-%m-%dT%H:%M:%SZ");
+            let patch = json::json!({
+                "spec": {
+                    "template": {
+                        "metadata": {
+                            "annotations": {Self::RESTARTED_AT: now.to_string()}
+                        }
+                    }
+                }
+            });
+            if self.dry_run != DryRun::Client {
+                let pp = kubeapi.patch_params(&self.field_manager, self.dry_run);
+                workload
+                    .patch(kubeapi, &pp, &api::Patch::Strategic(patch))
+                    .await?;
+            }
+            println!("{workload} restarted{}", self.dry_run.suffix());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,363 @@
+use std::fmt;
+
+use clap::ArgAction;
+use futures_util::TryStreamExt;
+use kube::runtime::watcher;
+use rkubectl_args::Duration;
+use rkubectl_kubeapi::watcher_error;
+
+use super::*;
+
+/// Show the status of the rollout.
+///
+///  By default 'rollout status' will watch the status of the latest rollout until it's done. If you don't want to
+/// wait for the rollout to finish then you can use --watch=false. Note that if a new rollout starts in-between, then
+/// 'rollout status' will continue watching the latest revision.
+///
+/// Examples:
+///   # Watch the rollout status of a deployment
+///   kubectl rollout status deployment/nginx
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutStatus {
+    /// The length of time to wait before ending watch, zero means never.
+    #[arg(long, value_parser = Duration::value_parser(), default_value = "0s")]
+    timeout: Duration,
+
+    /// Watch the status of the rollout until it's done.
+    #[arg(
+        short,
+        long,
+        default_value_t = true,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        action = ArgAction::Set,
+    )]
+    watch: bool,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+/// How far a rollout got
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Progress {
+    message: String,
+    done: bool,
+}
+
+/// Why a rollout can't be followed to the end
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Stopped {
+    Unsupported(String),
+    DeadlineExceeded(String),
+}
+
+impl RolloutStatus {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        for workload in Workload::from_args(&self.resources, kubeapi)? {
+            match workload.kind {
+                Kind::Deployment => {
+                    self.status(kubeapi.deployments()?, &workload, deployment)
+                        .await?;
+                }
+                Kind::StatefulSet => {
+                    self.status(kubeapi.statefulsets()?, &workload, statefulset)
+                        .await?;
+                }
+                Kind::DaemonSet => {
+                    self.status(kubeapi.daemonsets()?, &workload, daemonset)
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the progress of the rollout whenever it changes, until it is done
+    async fn status<K>(
+        &self,
+        api: api::Api<K>,
+        workload: &Workload,
+        progress: fn(&K) -> Result<Progress, Stopped>,
+    ) -> RkResult<()>
+    where
+        K: Clone + fmt::Debug + Send + serde::de::DeserializeOwned + kube::Resource + 'static,
+    {
+        if !self.watch {
+            let progress = progress(&api.get(&workload.name).await?)?;
+            print!("{}", progress.message);
+            return Ok(());
+        }
+
+        let watch = async {
+            let mut last = None;
+            let mut objects = Box::pin(watcher::watch_object(api, &workload.name));
+            while let Some(object) = objects.try_next().await.map_err(watcher_error)? {
+                let Some(object) = object else {
+                    return Err(RkError::not_found(format!("{workload} not found")));
+                };
+                let progress = progress(&object)?;
+                if last.as_ref() != Some(&progress.message) {
+                    print!("{}", progress.message);
+                    last = Some(progress.message);
+                }
+                if progress.done {
+                    break;
+                }
+            }
+            Ok(())
+        };
+        if self.timeout.is_zero() {
+            return watch.await;
+        }
+        tokio::time::timeout(self.timeout.duration(), watch)
+            .await
+            .unwrap_or_else(|_elapsed| {
+                Err(RkError::Timeout(format!(
+                    "timed out waiting for the rollout of {workload}"
+                )))
+            })
+    }
+}
+
+impl From<Stopped> for RkError {
+    fn from(stopped: Stopped) -> Self {
+        match stopped {
+            Stopped::Unsupported(text) => Self::invalid_arguments(text),
+            Stopped::DeadlineExceeded(text) => Self::Timeout(text),
+        }
+    }
+}
+
+impl Progress {
+    fn waiting(message: String) -> Result<Self, Stopped> {
+        Ok(Self {
+            message,
+            done: false,
+        })
+    }
+
+    fn done(message: String) -> Result<Self, Stopped> {
+        Ok(Self {
+            message,
+            done: true,
+        })
+    }
+}
+
+/// Same as kubectl's `DeploymentStatusViewer`
+fn deployment(deployment: &appsv1::Deployment) -> Result<Progress, Stopped> {
+    let name = deployment.name_any();
+    let generation = deployment.metadata.generation.unwrap_or_default();
+    let status = deployment.status.clone().unwrap_or_default();
+    if generation > status.observed_generation.unwrap_or_default() {
+        return Progress::waiting(
+            "Waiting for deployment spec update to be observed...\n".to_string(),
+        );
+    }
+
+    let deadline_exceeded = status.conditions.iter().flatten().any(|condition| {
+        condition.type_ == "Progressing"
+            && condition.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+    if deadline_exceeded {
+        return Err(Stopped::DeadlineExceeded(format!(
+            "deployment {name:?} exceeded its progress deadline"
+        )));
+    }
+
+    let updated = status.updated_replicas.unwrap_or_default();
+    let replicas = status.replicas.unwrap_or_default();
+    let available = status.available_replicas.unwrap_or_default();
+    let desired = deployment.spec.as_ref().and_then(|spec| spec.replicas);
+    match desired {
+        Some(desired) if updated < desired => Progress::waiting(format!(
+            "Waiting for deployment {name:?} rollout to finish: {updated} out of {desired} new replicas have been updated...\n"
+        )),
+        _ if replicas > updated => Progress::waiting(format!(
+            "Waiting for deployment {name:?} rollout to finish: {} old replicas are pending termination...\n",
+            replicas - updated
+        )),
+        _ if available < updated => Progress::waiting(format!(
+            "Waiting for deployment {name:?} rollout to finish: {available} of {updated} updated replicas are available...\n"
+        )),
+        _ => Progress::done(format!("deployment {name:?} successfully rolled out\n")),
+    }
+}
+
+/// Same as kubectl's `StatefulSetStatusViewer`
+fn statefulset(statefulset: &appsv1::StatefulSet) -> Result<Progress, Stopped> {
+    let spec = statefulset.spec.clone().unwrap_or_default();
+    let strategy = spec.update_strategy.unwrap_or_default();
+    if strategy
+        .type_
+        .as_deref()
+        .is_some_and(|type_| type_ != "RollingUpdate")
+    {
+        return Err(Stopped::Unsupported(
+            "rollout status is only available for RollingUpdate strategy type".to_string(),
+        ));
+    }
+
+    let generation = statefulset.metadata.generation.unwrap_or_default();
+    let status = statefulset.status.clone().unwrap_or_default();
+    let observed = status.observed_generation.unwrap_or_default();
+    if observed == 0 || generation > observed {
+        return Progress::waiting(
+            "Waiting for statefulset spec update to be observed...\n".to_string(),
+        );
+    }
+
+    let ready = status.ready_replicas.unwrap_or_default();
+    if let Some(replicas) = spec.replicas
+        && ready < replicas
+    {
+        return Progress::waiting(format!(
+            "Waiting for {} pods to be ready...\n",
+            replicas - ready
+        ));
+    }
+
+    let updated = status.updated_replicas.unwrap_or_default();
+    let partition = strategy.rolling_update.and_then(|update| update.partition);
+    if let (Some(partition), Some(replicas)) = (partition, spec.replicas) {
+        let target = replicas - partition;
+        if updated < target {
+            return Progress::waiting(format!(
+                "Waiting for partitioned roll out to finish: {updated} out of {target} new pods have been updated...\n"
+            ));
+        }
+        return Progress::done(format!(
+            "partitioned roll out complete: {updated} new pods have been updated...\n"
+        ));
+    }
+
+    let update_revision = status.update_revision.unwrap_or_default();
+    let current_revision = status.current_revision.unwrap_or_default();
+    if update_revision != current_revision {
+        return Progress::waiting(format!(
+            "waiting for statefulset rolling update to complete {updated} pods at revision {update_revision}...\n"
+        ));
+    }
+    Progress::done(format!(
+        "statefulset rolling update complete {} pods at revision {current_revision}...\n",
+        status.current_replicas.unwrap_or_default()
+    ))
+}
+
+/// Same as kubectl's `DaemonSetStatusViewer`
+fn daemonset(daemonset: &appsv1::DaemonSet) -> Result<Progress, Stopped> {
+    let name = daemonset.name_any();
+    let strategy = daemonset
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.update_strategy.as_ref())
+        .and_then(|strategy| strategy.type_.as_deref());
+    if strategy.is_some_and(|type_| type_ != "RollingUpdate") {
+        return Err(Stopped::Unsupported(
+            "rollout status is only available for RollingUpdate strategy type".to_string(),
+        ));
+    }
+
+    let generation = daemonset.metadata.generation.unwrap_or_default();
+    let status = daemonset.status.clone().unwrap_or_default();
+    if generation > status.observed_generation.unwrap_or_default() {
+        return Progress::waiting(
+            "Waiting for daemon set spec update to be observed...\n".to_string(),
+        );
+    }
+
+    let desired = status.desired_number_scheduled;
+    let updated = status.updated_number_scheduled.unwrap_or_default();
+    let available = status.number_available.unwrap_or_default();
+    if updated < desired {
+        return Progress::waiting(format!(
+            "Waiting for daemon set {name:?} rollout to finish: {updated} out of {desired} new pods have been updated...\n"
+        ));
+    }
+    if available < desired {
+        return Progress::waiting(format!(
+            "Waiting for daemon set {name:?} rollout to finish: {available} of {desired} updated pods are available...\n"
+        ));
+    }
+    Progress::done(format!("daemon set {name:?} successfully rolled out\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment_with(replicas: i32, status: json::Value) -> appsv1::Deployment {
+        json::from_value(json::json!({
+            "metadata": {"name": "web", "generation": 2},
+            "spec": {
+                "replicas": replicas,
+                "selector": {"matchLabels": {"app": "web"}},
+                "template": {}
+            },
+            "status": status,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn deployment_progress() {
+        let observing = deployment_with(3, json::json!({"observedGeneration": 1}));
+        assert_eq!(
+            deployment(&observing).unwrap().message,
+            "Waiting for deployment spec update to be observed...\n"
+        );
+
+        let updating = deployment_with(
+            3,
+            json::json!({"observedGeneration": 2, "replicas": 4, "updatedReplicas": 1}),
+        );
+        assert_eq!(
+            deployment(&updating).unwrap().message,
+            "Waiting for deployment \"web\" rollout to finish: 1 out of 3 new replicas have been updated...\n"
+        );
+
+        let terminating = deployment_with(
+            3,
+            json::json!({"observedGeneration": 2, "replicas": 4, "updatedReplicas": 3}),
+        );
+        assert_eq!(
+            deployment(&terminating).unwrap().message,
+            "Waiting for deployment \"web\" rollout to finish: 1 old replicas are pending termination...\n"
+        );
+
+        let done = deployment_with(
+            3,
+            json::json!({
+                "observedGeneration": 2,
+                "replicas": 3,
+                "updatedReplicas": 3,
+                "availableReplicas": 3
+            }),
+        );
+        let progress = deployment(&done).unwrap();
+        assert!(progress.done);
+        assert_eq!(
+            progress.message,
+            "deployment \"web\" successfully rolled out\n"
+        );
+
+        let stuck = deployment_with(
+            3,
+            json::json!({
+                "observedGeneration": 2,
+                "conditions": [{"type": "Progressing", "status": "False", "reason": "ProgressDeadlineExceeded"}]
+            }),
+        );
+        assert_eq!(
+            deployment(&stuck).unwrap_err(),
+            Stopped::DeadlineExceeded(
+                "deployment \"web\" exceeded its progress deadline".to_string()
+            )
+        );
+        let err = RkError::from(deployment(&stuck).unwrap_err());
+        assert!(matches!(err, RkError::Timeout(_)));
+    }
+}
@@ -0,0 +1,249 @@
+use super::*;
+
+/// Roll back to a previous rollout.
+///
+/// Examples:
+///   # Roll back to the previous deployment
+///   kubectl rollout undo deployment/abc
+///
+///   # Roll back to daemonset revision 3
+///   kubectl rollout undo daemonset/abc --to-revision=3
+///
+///   # Roll back to the previous deployment with dry-run
+///   kubectl rollout undo --dry-run=server deployment/abc
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct RolloutUndo {
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// The revision to rollback to. Default to 0 (last revision).
+    #[arg(long, default_value_t = 0)]
+    to_revision: i64,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl RolloutUndo {
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        for workload in Workload::from_args(&self.resources, kubeapi)? {
+            let object = workload.api(kubeapi)?.get(&workload.name).await?;
+            if object.data["spec"]["paused"] == true {
+                return Err(RkError::invalid_arguments(format!(
+                    "you cannot rollback a paused deployment; resume it first with 'kubectl rollout resume {workload}' and try again"
+                )));
+            }
+
+            let revisions = workload.revisions(kubeapi).await?;
+            let revision = self.revision(&workload, &revisions)?;
+            let (current, patch) = match workload.kind {
+                Kind::Deployment => (
+                    object.data["spec"]["template"].clone(),
+                    deployment_patch(&object, revision)?,
+                ),
+                Kind::StatefulSet | Kind::DaemonSet => (
+                    template_patch(&object.data["spec"]["template"]),
+                    api::Patch::Strategic(revision.data.clone()),
+                ),
+            };
+            if current == revision.data {
+                println!(
+                    "{workload} skipped rollback (current template already matches revision {})",
+                    revision.number
+                );
+                continue;
+            }
+
+            if self.dry_run != DryRun::Client {
+                let pp = kubeapi.patch_params("kubectl-rollout", self.dry_run);
+                workload.patch(kubeapi, &pp, &patch).await?;
+            }
+            println!("{workload} rolled back{}", self.dry_run.suffix());
+        }
+        Ok(())
+    }
+
+    /// The revision asked for, or the one before the current one
+    fn revision<'a>(
+        &self,
+        workload: &Workload,
+        revisions: &'a [Revision],
+    ) -> RkResult<&'a Revision> {
+        if self.to_revision > 0 {
+            revisions
+                .iter()
+                .find(|revision| revision.number == self.to_revision)
+                .ok_or_else(|| {
+                    RkError::not_found(format!(
+                        "unable to find specified revision {} in history",
+                        self.to_revision
+                    ))
+                })
+        } else {
+            revisions.iter().rev().nth(1).ok_or_else(|| {
+                RkError::not_found(format!("no rollout history found for {workload}"))
+            })
+        }
+    }
+}
+
+/// Same as kubectl, replaces the pod template while the deployment is unchanged,
+/// carrying over the change cause of the revision
+fn deployment_patch(
+    object: &api::DynamicObject,
+    revision: &Revision,
+) -> RkResult<api::Patch<json::Value>> {
+    let mut operations = vec![
+        json::json!({
+            "op": "test",
+            "path": "/metadata/resourceVersion",
+            "value": object.resource_version(),
+        }),
+        json::json!({
+            "op": "replace",
+            "path": "/spec/template",
+            "value": revision.data,
+        }),
+    ];
+    if let Some(change_cause) = &revision.change_cause {
+        let mut annotations = object.annotations().clone();
+        annotations.insert(Workload::CHANGE_CAUSE.to_string(), change_cause.clone());
+        operations.push(json::json!({
+            "op": "replace",
+            "path": "/metadata/annotations",
+            "value": annotations,
+        }));
+    }
+    let patch = json::from_value::<json_patch::Patch>(json::Value::Array(operations))
+        .map_err(kube::Error::SerdeError)?;
+    Ok(api::Patch::Json(patch))
+}
+
+/// The pod template the way ControllerRevisions record it
+fn template_patch(template: &json::Value) -> json::Value {
+    let mut template = template.clone();
+    if let Some(template) = template.as_object_mut() {
+        template.insert("$patch".to_string(), "replace".into());
+    }
+    json::json!({"spec": {"template": template}})
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        undo: RolloutUndo,
+    }
+
+    fn undo(args: &[&str]) -> RolloutUndo {
+        let args = ["undo", "deployment/web"].iter().chain(args);
+        Command::parse_from(args).undo
+    }
+
+    fn revision(number: i64, change_cause: Option<&str>) -> Revision {
+        Revision {
+            number,
+            change_cause: change_cause.map(ToString::to_string),
+            data: json::json!({
+                "metadata": {"labels": {"app": "web"}},
+                "spec": {"containers": [{"name": "web", "image": format!("nginx:1.{number}")}]},
+            }),
+        }
+    }
+
+    fn deployment() -> api::DynamicObject {
+        json::from_value(json::json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {
+                "name": "web",
+                "resourceVersion": "42",
+                "annotations": {"deployment.kubernetes.io/revision": "3"},
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn previous_revision() {
+        let workload = Workload {
+            kind: Kind::Deployment,
+            name: "web".to_string(),
+        };
+        let revisions = [revision(1, None), revision(2, None), revision(3, None)];
+        let number = |args: &[&str]| {
+            undo(args)
+                .revision(&workload, &revisions)
+                .map(|revision| revision.number)
+        };
+
+        assert_eq!(number(&[]).unwrap(), 2);
+        assert_eq!(number(&["--to-revision=1"]).unwrap(), 1);
+        let err = number(&["--to-revision=5"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to find specified revision 5 in history"
+        );
+        let err = undo(&[]).revision(&workload, &revisions[2..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no rollout history found for deployment.apps/web"
+        );
+    }
+
+    #[test]
+    fn deployment_template() {
+        let revision = revision(2, None);
+        let api::Patch::Json(patch) = deployment_patch(&deployment(), &revision).unwrap() else {
+            panic!("expecting a JSON patch");
+        };
+        assert_eq!(
+            json::to_value(patch).unwrap(),
+            json::json!([
+                {"op": "test", "path": "/metadata/resourceVersion", "value": "42"},
+                {"op": "replace", "path": "/spec/template", "value": revision.data},
+            ])
+        );
+    }
+
+    #[test]
+    fn deployment_change_cause() {
+        let revision = revision(2, Some("kubectl set image deployment/web web=nginx:1.2"));
+        let api::Patch::Json(patch) = deployment_patch(&deployment(), &revision).unwrap() else {
+            panic!("expecting a JSON patch");
+        };
+        assert_eq!(
+            json::to_value(patch).unwrap(),
+            json::json!([
+                {"op": "test", "path": "/metadata/resourceVersion", "value": "42"},
+                {"op": "replace", "path": "/spec/template", "value": revision.data},
+                {"op": "replace", "path": "/metadata/annotations", "value": {
+                    "deployment.kubernetes.io/revision": "3",
+                    "kubernetes.io/change-cause": "kubectl set image deployment/web web=nginx:1.2",
+                }},
+            ])
+        );
+    }
+
+    #[test]
+    fn template() {
+        let template = revision(3, None).data;
+        assert_eq!(
+            template_patch(&template),
+            json::json!({"spec": {"template": {
+                "$patch": "replace",
+                "metadata": {"labels": {"app": "web"}},
+                "spec": {"containers": [{"name": "web", "image": "nginx:1.3"}]},
+            }}})
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use super::*;
+
+/// A Deployment, StatefulSet or DaemonSet named on the command line
+#[derive(Clone, Debug)]
+pub(super) struct Workload {
+    pub(super) kind: Kind,
+    pub(super) name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Kind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+}
+
+/// One entry of the rollout history
+#[derive(Clone, Debug)]
+pub(super) struct Revision {
+    pub(super) number: i64,
+    pub(super) change_cause: Option<String>,
+    /// The pod template for Deployments, the patch restoring it for StatefulSets and DaemonSets
+    pub(super) data: json::Value,
+}
+
+impl Workload {
+    pub(super) const CHANGE_CAUSE: &str = "kubernetes.io/change-cause";
+    const REVISION: &str = "deployment.kubernetes.io/revision";
+    const POD_TEMPLATE_HASH: &str = "pod-template-hash";
+
+    pub(super) fn from_args(resources: &[String], kubeapi: &Kubeapi) -> RkResult<Vec<Self>> {
+        ResourceArg::from_strings(resources, kubeapi)
+            .map_err(RkError::invalid_arguments)?
+            .into_iter()
+            .map(|resource| {
+                let ResourceArg::NamedResource(resource) = resource else {
+                    return Err(RkError::invalid_arguments(
+                        "resource(s) were provided, but no name was specified",
+                    ));
+                };
+                let (_scope, dyntype) = resource.resource().api_resource();
+                let kind = match (dyntype.group.as_str(), dyntype.kind.as_str()) {
+                    ("apps", "Deployment") => Kind::Deployment,
+                    ("apps", "StatefulSet") => Kind::StatefulSet,
+                    ("apps", "DaemonSet") => Kind::DaemonSet,
+                    _ => {
+                        return Err(RkError::invalid_arguments(format!(
+                            "{resource} is not a Deployment, StatefulSet or DaemonSet"
+                        )));
+                    }
+                };
+                let name = resource.name().to_string();
+                Ok(Self { kind, name })
+            })
+            .collect()
+    }
+
+    pub(super) fn api(&self, kubeapi: &Kubeapi) -> kube::Result<api::Api<api::DynamicObject>> {
+        let dyntype = match self.kind {
+            Kind::Deployment => api::ApiResource::erase::<appsv1::Deployment>(&()),
+            Kind::StatefulSet => api::ApiResource::erase::<appsv1::StatefulSet>(&()),
+            Kind::DaemonSet => api::ApiResource::erase::<appsv1::DaemonSet>(&()),
+        };
+        kubeapi.dynamic_object_api(discovery::Scope::Namespaced, &dyntype)
+    }
+
+    pub(super) async fn patch(
+        &self,
+        kubeapi: &Kubeapi,
+        pp: &api::PatchParams,
+        patch: &api::Patch<json::Value>,
+    ) -> kube::Result<api::DynamicObject> {
+        self.api(kubeapi)?.patch(&self.name, pp, patch).await
+    }
+
+    /// Only Deployments can be paused
+    pub(super) async fn deployment(&self, kubeapi: &Kubeapi) -> RkResult<appsv1::Deployment> {
+        if self.kind != Kind::Deployment {
+            return Err(RkError::invalid_arguments(format!(
+                "{self} is not a Deployment, only Deployments can be paused and resumed"
+            )));
+        }
+        Ok(kubeapi.deployments()?.get(&self.name).await?)
+    }
+
+    /// The revisions, oldest first
+    pub(super) async fn revisions(&self, kubeapi: &Kubeapi) -> RkResult<Vec<Revision>> {
+        let mut revisions = match self.kind {
+            Kind::Deployment => {
+                let deployment = kubeapi.deployments()?.get(&self.name).await?;
+                let selector = deployment.spec.as_ref().map(|spec| &spec.selector);
+                kubeapi
+                    .replicasets()?
+                    .list(&list_params(selector))
+                    .await?
+                    .items
+                    .into_iter()
+                    .filter(|replicaset| is_owned_by(replicaset, &deployment))
+                    .filter_map(replicaset_revision)
+                    .collect::<Vec<_>>()
+            }
+            Kind::StatefulSet => {
+                let statefulset = kubeapi.statefulsets()?.get(&self.name).await?;
+                let selector = statefulset.spec.as_ref().map(|spec| &spec.selector);
+                controller_revisions(kubeapi, &statefulset, selector).await?
+            }
+            Kind::DaemonSet => {
+                let daemonset = kubeapi.daemonsets()?.get(&self.name).await?;
+                let selector = daemonset.spec.as_ref().map(|spec| &spec.selector);
+                controller_revisions(kubeapi, &daemonset, selector).await?
+            }
+        };
+        revisions.sort_by_key(|revision| revision.number);
+        Ok(revisions)
+    }
+}
+
+/// Same as kubectl `-o name`, e.g. `deployment.apps/web`
+impl fmt::Display for Workload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            Kind::Deployment => "deployment",
+            Kind::StatefulSet => "statefulset",
+            Kind::DaemonSet => "daemonset",
+        };
+        write!(f, "{kind}.apps/{}", self.name)
+    }
+}
+
+fn list_params(selector: Option<&metav1::LabelSelector>) -> api::ListParams {
+    let selector = selector
+        .cloned()
+        .and_then(|selector| kube::core::Selector::try_from(selector).ok());
+    match selector {
+        Some(selector) => api::ListParams::default().labels_from(&selector),
+        None => api::ListParams::default(),
+    }
+}
+
+fn is_owned_by(object: &impl kube::Resource, owner: &impl kube::Resource) -> bool {
+    let uid = owner.meta().uid.as_deref();
+    object
+        .owner_references()
+        .iter()
+        .any(|reference| Some(reference.uid.as_str()) == uid)
+}
+
+fn replicaset_revision(replicaset: appsv1::ReplicaSet) -> Option<Revision> {
+    let annotations = replicaset.annotations();
+    let number = annotations.get(Workload::REVISION)?.parse().ok()?;
+    let change_cause = annotations.get(Workload::CHANGE_CAUSE).cloned();
+    let mut template = replicaset.spec?.template?;
+    // Added by the deployment controller to tell the replica sets apart
+    if let Some(labels) = template
+        .metadata
+        .as_mut()
+        .and_then(|metadata| metadata.labels.as_mut())
+    {
+        labels.remove(Workload::POD_TEMPLATE_HASH);
+    }
+    let data = json::to_value(template).ok()?;
+    Some(Revision {
+        number,
+        change_cause,
+        data,
+    })
+}
+
+async fn controller_revisions(
+    kubeapi: &Kubeapi,
+    owner: &impl kube::Resource,
+    selector: Option<&metav1::LabelSelector>,
+) -> RkResult<Vec<Revision>> {
+    let revisions = kubeapi
+        .controllerrevisions()?
+        .list(&list_params(selector))
+        .await?
+        .items
+        .into_iter()
+        .filter(|revision| is_owned_by(revision, owner))
+        .map(|revision| Revision {
+            number: revision.revision,
+            change_cause: revision.annotations().get(Workload::CHANGE_CAUSE).cloned(),
+            data: revision.data.map(|data| data.0).unwrap_or_default(),
+        })
+        .collect();
+    Ok(revisions)
+}
@@ -0,0 +1,216 @@
+use std::time;
+
+use rkubectl_args::Duration;
+use rkubectl_resource::NamedResource;
+
+use super::*;
+
+/// Set a new size for a deployment, replica set, replication controller, or stateful set.
+///
+///  Scale also allows users to specify one or more preconditions for the scale action.
+///
+///  If --current-replicas or --resource-version is specified, it is validated before the scale is attempted, and it is
+/// guaranteed that the precondition holds true when the scale is sent to the server.
+///
+///  Any resource exposing the scale subresource can be scaled, including custom resources.
+///
+/// Examples:
+///   # Scale a replica set named 'foo' to 3
+///   kubectl scale --replicas=3 rs/foo
+///
+///   # If the deployment named mysql's current size is 2, scale mysql to 3
+///   kubectl scale --current-replicas=2 --replicas=3 deployment/mysql
+///
+///   # Scale multiple replication controllers
+///   kubectl scale --replicas=5 rc/example1 rc/example2 rc/example3
+///
+///   # Scale all deployments labelled app=web to 2, waiting for them to be scaled
+///   kubectl scale --replicas=2 deployments -l app=web --timeout=1m
+#[derive(Clone, Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Scale {
+    /// Select all resources in the namespace of the specified resource types.
+    #[arg(long)]
+    all: bool,
+
+    /// Precondition for current size. Requires that the current size of the resource match this value in order to scale.
+    #[arg(long)]
+    current_replicas: Option<i32>,
+
+    /// If client strategy, only print the object that would be sent, without sending it.
+    /// If server strategy, submit server-side request without persisting the resource.
+    #[arg(long, value_enum, default_value_t = DryRun::None)]
+    dry_run: DryRun,
+
+    /// The new desired number of replicas. Required.
+    #[arg(long, required = true)]
+    replicas: i32,
+
+    /// Precondition for resource version. Requires that the current resource version match this value in order to scale.
+    #[arg(long)]
+    resource_version: Option<String>,
+
+    /// The length of time to wait before giving up on a scale operation, zero means don't wait.
+    /// Any other values should contain a corresponding time unit (e.g. 1s, 2m, 3h).
+    #[arg(long, value_parser = Duration::value_parser(), default_value = "0s")]
+    timeout: Duration,
+
+    #[arg(required = true, value_name = "TYPE NAME | TYPE/NAME")]
+    resources: Vec<String>,
+}
+
+impl Scale {
+    const FIELD_MANAGER: &str = "kubectl-scale";
+    const POLL_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+    pub async fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let mut scaled = vec![];
+        for (resource, namespace) in self.targets(kubeapi).await? {
+            if !resource.resource().has_scale(kubeapi) {
+                return Err(RkError::invalid_arguments(format!(
+                    "{resource} does not expose the scale subresource"
+                )));
+            }
+            let (scope, ref dyntype) = resource.resource().api_resource();
+            let api = kubeapi.object_api(scope, dyntype, namespace.as_deref())?;
+            self.scale(kubeapi, &api, resource.name()).await?;
+            println!("{resource} scaled{}", self.dry_run.suffix());
+            scaled.push((resource, api));
+        }
+
+        if self.timeout.is_zero() || self.dry_run != DryRun::None {
+            return Ok(());
+        }
+        let deadline = time::Instant::now() + self.timeout.duration();
+        for (resource, api) in scaled {
+            loop {
+                let scale = api.get_scale(resource.name()).await?;
+                let replicas = scale.status.map(|status| status.replicas);
+                if replicas == Some(self.replicas) {
+                    break;
+                }
+                if time::Instant::now() >= deadline {
+                    return Err(RkError::Timeout(format!(
+                        "timed out waiting for {resource} to be scaled to {} replicas",
+                        self.replicas
+                    )));
+                }
+                tokio::time::sleep(Self::POLL_INTERVAL).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// The named resources, or those selected with --all or a label selector, with their namespace
+    async fn targets(&self, kubeapi: &Kubeapi) -> RkResult<Vec<(NamedResource, Option<String>)>> {
+        let resources = ResourceArg::from_strings(&self.resources, kubeapi)
+            .map_err(RkError::invalid_arguments)?;
+        let targets = ResourceArg::select(resources, kubeapi, self.all).await?;
+        if targets.is_empty() {
+            return Err(RkError::not_found("No resources found"));
+        }
+        Ok(targets)
+    }
+
+    /// Updates the scale subresource, replacing it when there are preconditions,
+    /// so that the server rejects it if the object changed since they were checked
+    async fn scale(
+        &self,
+        kubeapi: &Kubeapi,
+        api: &api::Api<api::DynamicObject>,
+        name: &str,
+    ) -> RkResult<()> {
+        let preconditions = self.current_replicas.is_some() || self.resource_version.is_some();
+        if preconditions {
+            let mut scale = api.get_scale(name).await?;
+            self.check_preconditions(&scale)?;
+            if self.dry_run != DryRun::Client {
+                scale.spec.get_or_insert_default().replicas = Some(self.replicas);
+                let pp = api::PostParams {
+                    dry_run: self.dry_run == DryRun::Server,
+                    field_manager: Some(Self::FIELD_MANAGER.to_string()),
+                };
+                api.replace_scale(name, &pp, &scale).await?;
+            }
+        } else if self.dry_run != DryRun::Client {
+            let pp = kubeapi.patch_params(Self::FIELD_MANAGER, self.dry_run);
+            let patch = json::json!({"spec": {"replicas": self.replicas}});
+            api.patch_scale(name, &pp, &api::Patch::Merge(patch))
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn check_preconditions(&self, scale: &autoscalingv1::Scale) -> RkResult<()> {
+        if let Some(expected) = self.current_replicas {
+            let actual = scale
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.replicas)
+                .unwrap_or_default();
+            if actual != expected {
+                return Err(RkError::invalid_arguments(format!(
+                    "Expected replicas to be {expected}, was {actual}"
+                )));
+            }
+        }
+        if let Some(expected) = &self.resource_version {
+            let actual = scale
+                .metadata
+                .resource_version
+                .as_deref()
+                .unwrap_or_default();
+            if actual != expected {
+                return Err(RkError::invalid_arguments(format!(
+                    "Expected resource version to be {expected}, was {actual}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        scale: Scale,
+    }
+
+    #[test]
+    fn preconditions() {
+        let scale = json::from_value::<autoscalingv1::Scale>(json::json!({
+            "metadata": {"name": "web", "resourceVersion": "42"},
+            "spec": {"replicas": 2}
+        }))
+        .unwrap();
+        let command = |args: &[&str]| {
+            let args = ["scale", "--replicas=3", "deployment/web"]
+                .iter()
+                .chain(args);
+            Command::parse_from(args).scale
+        };
+
+        assert!(command(&[]).check_preconditions(&scale).is_ok());
+        assert!(
+            command(&["--current-replicas=2", "--resource-version=42"])
+                .check_preconditions(&scale)
+                .is_ok()
+        );
+        let err = command(&["--current-replicas=3"])
+            .check_preconditions(&scale)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Expected replicas to be 3, was 2");
+        assert!(
+            command(&["--resource-version=41"])
+                .check_preconditions(&scale)
+                .is_err()
+        );
+    }
+}
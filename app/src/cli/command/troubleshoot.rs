@@ -1,28 +1,48 @@
 use super::*;
 
+pub use attach::Attach;
 pub use auth::Auth;
+pub use cp::Cp;
 pub use debug::Debug;
+pub use describe::Describe;
+pub use events::Events;
+pub use exec::Exec;
+pub use logs::Logs;
+pub use portforward::PortForward;
+pub use proxy::Proxy;
 
+use remote::Terminal;
+
+mod attach;
 mod auth;
+mod cp;
 mod debug;
+mod describe;
+mod events;
+mod exec;
+mod logs;
+mod portforward;
+mod proxy;
+mod remote;
+mod target;
 
 /// Troubleshooting and Debugging Commands
 #[derive(Clone, Debug, Subcommand)]
 pub enum TroubleshootingDebugging {
     /// Show details of a specific resource or group of resources
-    Describe,
+    Describe(Describe),
     /// Print the logs for a container in a pod
-    Logs,
+    Logs(Logs),
     /// Attach to a running container
-    Attach,
+    Attach(Attach),
     /// Execute a command in a container
-    Exec,
+    Exec(Exec),
     /// Forward one or more local ports to a pod
-    PortForward,
+    PortForward(PortForward),
     /// Run a proxy to the Kubernetes API server
-    Proxy,
+    Proxy(Proxy),
     /// Copy files and directories to and from containers
-    Cp,
+    Cp(Cp),
 
     /// Inspect authorization
     #[command(subcommand)]
@@ -31,22 +51,22 @@ pub enum TroubleshootingDebugging {
     /// Create debugging sessions for troubleshooting workloads and nodes
     Debug,
     /// List events
-    Events,
+    Events(Events),
 }
 
 impl TroubleshootingDebugging {
     pub async fn exec(self, context: &Context) -> RkResult<()> {
         match self {
-            Self::Describe => Err(RkError::todo()),
-            Self::Logs => Err(RkError::todo()),
-            Self::Attach => Err(RkError::todo()),
-            Self::Exec => Err(RkError::todo()),
-            Self::PortForward => Err(RkError::todo()),
-            Self::Proxy => Err(RkError::todo()),
-            Self::Cp => Err(RkError::todo()),
+            Self::Describe(describe) => describe.exec(context).await,
+            Self::Logs(logs) => logs.exec(context).await,
+            Self::Attach(attach) => attach.exec(context).await,
+            Self::Exec(exec) => exec.exec(context).await,
+            Self::PortForward(port_forward) => port_forward.exec(context).await,
+            Self::Proxy(proxy) => proxy.exec(context).await,
+            Self::Cp(cp) => cp.exec(context).await,
             Self::Auth(auth) => auth.exec(context).await,
             Self::Debug => Err(RkError::todo()),
-            Self::Events => Err(RkError::todo()),
+            Self::Events(events) => events.exec(context).await,
         }
     }
 }
@@ -0,0 +1,57 @@
+use super::*;
+
+/// Attach to a process that is already running inside an existing container.
+///
+///  The pod is picked by name, or as the first pod of a TYPE/NAME (e.g. deployment/web).
+#[derive(Clone, Debug, Args)]
+pub struct Attach {
+    /// Container name. If omitted, use the kubectl.kubernetes.io/default-container annotation for selecting the
+    /// container to be attached or the first container in the pod will be chosen
+    #[arg(short, long)]
+    container: Option<String>,
+
+    /// Only print output from the remote session
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Pass stdin to the container
+    #[arg(short = 'i', long)]
+    stdin: bool,
+
+    /// Stdin is a TTY
+    #[arg(short, long)]
+    tty: bool,
+
+    /// Pod name or TYPE/NAME, e.g. deployment/web
+    #[arg(value_name = "POD | TYPE/NAME")]
+    target: String,
+}
+
+impl Attach {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let pod = target::pod(kubeapi, &self.target).await?;
+        let container = self
+            .container
+            .clone()
+            .or_else(|| target::default_container(&pod, self.quiet))
+            .ok_or_else(|| {
+                RkError::not_found(format!("No containers in pod {}", pod.name_any()))
+            })?;
+
+        let terminal = Terminal::new(self.stdin, self.tty, self.quiet);
+        let ap = terminal.attach_params(&container);
+        let namespace = pod.namespace().unwrap_or_default();
+        let process = kubeapi
+            .namespaced_pods(&namespace)?
+            .attach(&pod.name_any(), &ap)
+            .await?;
+        if terminal.tty() && !self.quiet {
+            eprintln!("If you don't see a command prompt, try pressing enter.");
+        }
+        match terminal.run(process).await? {
+            0 => Ok(()),
+            code => Err(RkError::RemoteExit(code)),
+        }
+    }
+}
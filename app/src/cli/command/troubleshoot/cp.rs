@@ -0,0 +1,416 @@
+use std::io::IsTerminal;
+use std::io::Read;
+use std::io::Write;
+use std::path::Component;
+
+use kube::api::AttachedProcess;
+use rkubectl_args::FileSpec;
+use tokio_util::io::SyncIoBridge;
+
+use super::*;
+
+/// Copy files and directories to and from containers.
+///
+///  Requires the 'tar' binary to be present in the container image. Use [[NAMESPACE/]POD:]PATH for a file or
+/// directory in a container, and a plain path for a local one.
+#[derive(Clone, Debug, Args)]
+pub struct Cp {
+    /// Container name. If omitted, use the kubectl.kubernetes.io/default-container annotation for selecting the
+    /// container to be attached or the first container in the pod will be chosen
+    #[arg(short, long)]
+    container: Option<String>,
+
+    /// The copied file/directory's ownership and permissions will not be preserved
+    #[arg(long)]
+    no_preserve: bool,
+
+    /// Source, local path or [[NAMESPACE/]POD:]PATH
+    #[arg(value_name = "SRC", value_parser = FileSpec::value_parser())]
+    source: FileSpec,
+
+    /// Destination, local path or [[NAMESPACE/]POD:]PATH
+    #[arg(value_name = "DEST", value_parser = FileSpec::value_parser())]
+    destination: FileSpec,
+}
+
+impl Cp {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        match (&self.source, &self.destination) {
+            (
+                FileSpec::Remote {
+                    namespace,
+                    pod,
+                    path,
+                },
+                FileSpec::Local(local),
+            ) => {
+                let remote = Remote::new(context, namespace.as_deref(), pod, self).await?;
+                self.download(&remote, path, local).await
+            }
+            (
+                FileSpec::Local(local),
+                FileSpec::Remote {
+                    namespace,
+                    pod,
+                    path,
+                },
+            ) => {
+                let remote = Remote::new(context, namespace.as_deref(), pod, self).await?;
+                self.upload(&remote, local, path).await
+            }
+            (FileSpec::Local(_), FileSpec::Local(_)) => Err(RkError::invalid_arguments(
+                "one of src or dest must be a remote file specification",
+            )),
+            (FileSpec::Remote { .. }, FileSpec::Remote { .. }) => Err(RkError::invalid_arguments(
+                "one of src or dest must be a local file specification",
+            )),
+        }
+    }
+
+    async fn download(&self, remote: &Remote, path: &str, local: &Path) -> RkResult<()> {
+        let (directory, base) = split(path)?;
+        let command = ["tar", "cf", "-", "-C", directory, base];
+        let mut process = remote.run(&command, false).await?;
+
+        let stdout = process
+            .stdout()
+            .ok_or_else(|| io::Error::other("no stdout from remote tar"))?;
+        let reader = SyncIoBridge::new(stdout);
+        let destination = local.to_path_buf();
+        let base = base.to_string();
+        let preserve = !self.no_preserve;
+        let extracting = tokio::task::spawn_blocking(move || {
+            let mut reader = Progress::new(reader, "Received");
+            extract(&mut reader, &destination, &base, preserve)?;
+            reader.finish();
+            io::Result::Ok(())
+        });
+
+        let copying = forward_stderr(&mut process);
+        let (extracted, _) = tokio::join!(extracting, copying);
+        extracted.map_err(io::Error::other)??;
+        remote.finish(process).await
+    }
+
+    async fn upload(&self, remote: &Remote, local: &Path, path: &str) -> RkResult<()> {
+        if !local.exists() {
+            return Err(RkError::not_found(format!(
+                "{}: no such file or directory",
+                local.display()
+            )));
+        }
+        let (directory, name) = if remote.is_dir(path).await? {
+            let name = local
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| {
+                    RkError::invalid_arguments(format!("invalid source '{}'", local.display()))
+                })?;
+            (path, name)
+        } else {
+            split(path)?
+        };
+
+        let command = if self.no_preserve {
+            vec![
+                "tar",
+                "--no-same-permissions",
+                "--no-same-owner",
+                "-xmf",
+                "-",
+                "-C",
+                directory,
+            ]
+        } else {
+            vec!["tar", "-xmf", "-", "-C", directory]
+        };
+        let mut process = remote.run(&command, true).await?;
+
+        let stdin = process
+            .stdin()
+            .ok_or_else(|| io::Error::other("no stdin for remote tar"))?;
+        let writer = SyncIoBridge::new(stdin);
+        let source = local.to_path_buf();
+        let name = name.to_string();
+        let archiving = tokio::task::spawn_blocking(move || {
+            let mut writer = Progress::new(writer, "Sent");
+            archive(&mut writer, &source, &name)?;
+            // Closing stdin is what lets the remote tar finish
+            writer.finish().shutdown()
+        });
+
+        let copying = forward_stderr(&mut process);
+        let (archived, _) = tokio::join!(archiving, copying);
+        archived.map_err(io::Error::other)??;
+        remote.finish(process).await
+    }
+}
+
+/// Pod and container on the remote end of the copy
+struct Remote {
+    pods: api::Api<corev1::Pod>,
+    pod: String,
+    container: String,
+}
+
+impl Remote {
+    async fn new(context: &Context, namespace: Option<&str>, pod: &str, cp: &Cp) -> RkResult<Self> {
+        let kubeapi = context.kubeapi();
+        let pods = match namespace {
+            Some(namespace) => kubeapi.namespaced_pods(namespace)?,
+            None => kubeapi.pods()?,
+        };
+        let object = pods.get(pod).await?;
+        let container = cp
+            .container
+            .clone()
+            .or_else(|| target::default_container(&object, false))
+            .ok_or_else(|| RkError::not_found(format!("No containers in pod {pod}")))?;
+        let pod = pod.to_string();
+        Ok(Self {
+            pods,
+            pod,
+            container,
+        })
+    }
+
+    async fn run(&self, command: &[&str], stdin: bool) -> RkResult<AttachedProcess> {
+        let ap = api::AttachParams::default()
+            .container(&self.container)
+            .stdin(stdin)
+            .stdout(true)
+            .stderr(true);
+        let process = self
+            .pods
+            .exec(&self.pod, command.iter().copied(), &ap)
+            .await?;
+        Ok(process)
+    }
+
+    async fn is_dir(&self, path: &str) -> RkResult<bool> {
+        let process = self.run(&["test", "-d", path], false).await?;
+        Ok(Self::wait(process).await? == 0)
+    }
+
+    async fn finish(&self, process: AttachedProcess) -> RkResult<()> {
+        match Self::wait(process).await? {
+            0 => Ok(()),
+            code => Err(RkError::RemoteExit(code)),
+        }
+    }
+
+    async fn wait(mut process: AttachedProcess) -> RkResult<i32> {
+        let status = match process.take_status() {
+            Some(status) => status.await,
+            None => None,
+        };
+        process.join().await.map_err(io::Error::other)?;
+        Ok(remote::exit_code(status.as_ref()))
+    }
+}
+
+async fn forward_stderr(process: &mut AttachedProcess) {
+    if let Some(mut stderr) = process.stderr() {
+        let _ = tokio::io::copy(&mut stderr, &mut tokio::io::stderr()).await;
+    }
+}
+
+/// Splits a remote path into the directory to run tar in, and the name to archive or extract
+fn split(path: &str) -> RkResult<(&str, &str)> {
+    let trimmed = path.trim_end_matches('/');
+    let (directory, base) = match trimmed.rsplit_once('/') {
+        Some(("", base)) => ("/", base),
+        Some((directory, base)) => (directory, base),
+        None => (".", trimmed),
+    };
+    if base.is_empty() || base == "." || base == ".." {
+        return Err(RkError::invalid_arguments(format!(
+            "cannot copy '{path}', name a file or directory"
+        )));
+    }
+    Ok((directory, base))
+}
+
+fn archive(writer: impl Write, source: &Path, name: &str) -> io::Result<()> {
+    let mut builder = tar::Builder::new(writer);
+    builder.follow_symlinks(false);
+    if source.is_dir() {
+        builder.append_dir_all(name, source)?;
+    } else {
+        builder.append_path_with_name(source, name)?;
+    }
+    builder.finish()
+}
+
+/// Unpacks the remote tarball of `base` into `destination`, inside it when it is an existing directory,
+/// or as the destination itself otherwise. Entries escaping the destination, as well as links, are skipped.
+fn extract(reader: impl Read, destination: &Path, base: &str, preserve: bool) -> io::Result<()> {
+    let into = destination.is_dir();
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        let Some(relative) = sanitize(&path) else {
+            eprintln!(
+                "warning: skipping {}, it points outside of the destination",
+                path.display()
+            );
+            continue;
+        };
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            eprintln!("warning: skipping link {}", path.display());
+            continue;
+        }
+
+        let target = if into {
+            destination.join(&relative)
+        } else {
+            match relative.strip_prefix(base) {
+                // A single file, copied as the destination itself
+                Ok(relative) if relative.as_os_str().is_empty() => destination.to_path_buf(),
+                Ok(relative) => destination.join(relative),
+                Err(_) => continue,
+            }
+        };
+        if let Some(parent) = target.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        entry.set_preserve_permissions(preserve);
+        entry.set_preserve_mtime(preserve);
+        entry.unpack(&target)?;
+    }
+    Ok(())
+}
+
+/// Relative form of an archive path, or `None` when it is absolute or climbs up with `..`
+fn sanitize(path: &Path) -> Option<PathBuf> {
+    let mut relative = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(relative)
+}
+
+/// Byte counter for either direction, reported on a terminal once the transfer gets large
+struct Progress<T> {
+    inner: T,
+    label: &'static str,
+    bytes: u64,
+    reported: u64,
+    enabled: bool,
+}
+
+impl<T> Progress<T> {
+    const STEP: u64 = 1024 * 1024;
+
+    fn new(inner: T, label: &'static str) -> Self {
+        Self {
+            inner,
+            label,
+            bytes: 0,
+            reported: 0,
+            enabled: io::stderr().is_terminal(),
+        }
+    }
+
+    fn finish(self) -> T {
+        if self.reported > 0 {
+            eprintln!("\r{} {}", self.label, size::Size::from_bytes(self.bytes));
+        }
+        self.inner
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        self.bytes += bytes as u64;
+        if self.enabled && self.bytes >= self.reported + Self::STEP {
+            self.reported = self.bytes;
+            eprint!("\r{} {}", self.label, size::Size::from_bytes(self.bytes));
+        }
+    }
+}
+
+impl<T: Read> Read for Progress<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.advance(read);
+        Ok(read)
+    }
+}
+
+impl<T: Write> Write for Progress<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.advance(written);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tarball(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(vec![]);
+        for (path, data) in entries {
+            let mut header = tar::Header::new_old();
+            // Written directly, as `set_path()` refuses the malicious paths this is about
+            header.as_old_mut().name[..path.len()].copy_from_slice(path.as_bytes());
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_entry_type(tar::EntryType::Regular);
+            header.set_cksum();
+            builder.append(&header, *data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn extract_stays_in_destination() {
+        let tmp = tempfile::tempdir().unwrap();
+        let destination = tmp.path().join("dest");
+        let tarball = tarball(&[
+            ("conf/app.yaml", b"key: value"),
+            ("conf/../../escaped", b"nope"),
+            ("/etc/absolute", b"nope"),
+        ]);
+
+        extract(tarball.as_slice(), &destination, "conf", true).unwrap();
+
+        let copied = fs::read_to_string(destination.join("app.yaml")).unwrap();
+        assert_eq!(copied, "key: value");
+        assert!(!tmp.path().join("escaped").exists());
+        assert!(!destination.join("etc").exists());
+    }
+
+    #[test]
+    fn extract_single_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let destination = tmp.path().join("hosts");
+        let tarball = tarball(&[("hosts", b"127.0.0.1 localhost")]);
+
+        extract(tarball.as_slice(), &destination, "hosts", true).unwrap();
+
+        let copied = fs::read_to_string(&destination).unwrap();
+        assert_eq!(copied, "127.0.0.1 localhost");
+    }
+
+    #[test]
+    fn split_remote_paths() {
+        assert_eq!(split("/tmp/foo").unwrap(), ("/tmp", "foo"));
+        assert_eq!(split("/tmp/foo/").unwrap(), ("/tmp", "foo"));
+        assert_eq!(split("/foo").unwrap(), ("/", "foo"));
+        assert_eq!(split("foo").unwrap(), (".", "foo"));
+        assert!(split("/").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use clap::ArgAction;
+
+use super::*;
+
+/// Show details of a specific resource or group of resources.
+///
+///  Print a detailed description of the selected resources, including related resources such as events or controllers.
+/// You may select a single object by name, all objects of that type, or a label selector.
+///
+///  Use "kubectl api-resources" for a complete list of supported resources.
+#[derive(Clone, Debug, Args)]
+pub struct Describe {
+    /// If true, display events related to the described object.
+    #[arg(long, default_value_t = true, action = ArgAction::Set)]
+    show_events: bool,
+
+    /// Resources to describe
+    #[arg(required = true)]
+    resources: Vec<String>,
+}
+
+impl Describe {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let mut described = vec![];
+        for resource in self.resources(kubeapi)? {
+            for object in resource.dynamic_objects(kubeapi).await? {
+                let events = if self.show_events {
+                    Some(self.events(kubeapi, &object).await?)
+                } else {
+                    None
+                };
+                let object = rkubectl_ui::describable(object);
+                described.push(rkubectl_ui::describe(object.as_ref(), events.as_deref()));
+            }
+        }
+
+        if described.is_empty() {
+            eprintln!("No resources found");
+        } else {
+            context.print_deprecated(described.join("\n").trim_end());
+        }
+        Ok(())
+    }
+
+    async fn events(
+        &self,
+        kubeapi: &Kubeapi,
+        object: &api::DynamicObject,
+    ) -> kube::Result<Vec<corev1::Event>> {
+        let Some(uid) = object.uid() else {
+            return Ok(vec![]);
+        };
+        let namespace = object.namespace();
+        kubeapi.object_events(namespace.as_deref(), &uid).await
+    }
+
+    fn resources(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<ResourceArg>> {
+        ResourceArg::from_strings(&self.resources, kubeapi)
+            .inspect(|resources| info!(args=?self.resources, ?resources))
+            .map_err(|_err| kube::Error::LinesCodecMaxLineLengthExceeded)
+    }
+}
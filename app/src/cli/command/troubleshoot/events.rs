@@ -0,0 +1,223 @@
+use clap::ValueEnum;
+use futures_util::StreamExt;
+use k8s::eventsv1;
+use rkubectl_ext::EventExt;
+use rkubectl_ui::RowPrinter;
+
+use super::*;
+
+/// Display events.
+///
+///  Prints a table of the most important information about events. You can request events for a namespace, for all
+/// namespaces, or filtered to only those pertaining to a specified resource. Events are sorted by the time they were
+/// last seen, most recent last.
+#[derive(Clone, Debug, Args)]
+pub struct Events {
+    /// Filter events to only those pertaining to the specified resource.
+    #[arg(long = "for", value_name = "TYPE/NAME")]
+    regarding: Option<String>,
+
+    /// Output only events of given types.
+    #[arg(long, value_delimiter = ',', value_enum, ignore_case = true)]
+    types: Vec<EventType>,
+
+    /// After listing the requested events, watch for more events.
+    #[arg(short, long)]
+    watch: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "PascalCase")]
+enum EventType {
+    Normal,
+    Warning,
+}
+
+impl Events {
+    const GONE: u16 = 410;
+
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let namespace = kubeapi.show_namespace();
+        let output = context.output_deprecated();
+        let params = ShowParams::default();
+
+        let regarding = self.regarding(kubeapi)?;
+        let regarding = regarding
+            .as_ref()
+            .map(|(kind, name)| (kind.as_str(), name.as_str()));
+        let (events_api, list) = kubeapi.list_events(regarding).await?;
+        let mut version = list.metadata.resource_version.unwrap_or_default();
+        let events = self.select(list.items);
+
+        let mut printer = RowPrinter::default();
+        if events.is_empty() && !self.watch {
+            eprintln!("No events found.");
+            return Ok(());
+        }
+        let events = boxed(events);
+        println!("{}", printer.batch(&events, namespace, params, output));
+        if !self.watch {
+            return Ok(());
+        }
+
+        loop {
+            let mut changes = kubeapi
+                .watch_events(events_api, regarding, &version)
+                .await?;
+            while let Some(change) = changes.next().await {
+                let event = match change? {
+                    api::WatchEvent::Added(event) | api::WatchEvent::Modified(event) => event,
+                    api::WatchEvent::Deleted(_) => continue,
+                    api::WatchEvent::Bookmark(bookmark) => {
+                        version = bookmark.metadata.resource_version;
+                        continue;
+                    }
+                    api::WatchEvent::Error(status) if status.code == Self::GONE => {
+                        // Too old to resume, start over from the current state without replaying it
+                        let (_, list) = kubeapi.list_events(regarding).await?;
+                        version = list.metadata.resource_version.unwrap_or_default();
+                        break;
+                    }
+                    api::WatchEvent::Error(status) => return Err(kube::Error::Api(status).into()),
+                };
+                if let Some(resource_version) = event.resource_version() {
+                    version = resource_version;
+                }
+                if self.accept(&event) {
+                    let events = boxed(vec![event]);
+                    println!("{}", printer.batch(&events, namespace, params, output));
+                }
+            }
+        }
+    }
+
+    /// Kind and name of the `--for` object
+    fn regarding(&self, kubeapi: &Kubeapi) -> RkResult<Option<(String, String)>> {
+        let Some(regarding) = self.regarding.as_deref() else {
+            return Ok(None);
+        };
+        let invalid =
+            || RkError::invalid_arguments(format!("invalid --for '{regarding}', use TYPE/NAME"));
+        if !regarding.contains('/') {
+            return Err(invalid());
+        }
+        let resources =
+            ResourceArg::from_strings(&[regarding.to_string()], kubeapi).map_err(|_| invalid())?;
+        let Some(ResourceArg::NamedResource(resource)) = resources.first() else {
+            return Err(invalid());
+        };
+        let (_scope, dyntype) = resource.resource().api_resource();
+        Ok(Some((dyntype.kind, resource.name().to_string())))
+    }
+
+    /// The events of the requested types, oldest first
+    fn select(&self, events: Vec<eventsv1::Event>) -> Vec<eventsv1::Event> {
+        let mut events = events
+            .into_iter()
+            .filter(|event| self.accept(event))
+            .collect::<Vec<_>>();
+        events.sort_by_key(|event| event.last_seen());
+        events
+    }
+
+    fn accept(&self, event: &eventsv1::Event) -> bool {
+        self.types.is_empty()
+            || self.types.iter().any(|type_| {
+                event
+                    .type_
+                    .as_deref()
+                    .is_some_and(|event_type| event_type.eq_ignore_ascii_case(type_.as_str()))
+            })
+    }
+}
+
+impl EventType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Warning => "Warning",
+        }
+    }
+}
+
+fn boxed(events: Vec<eventsv1::Event>) -> Vec<Box<dyn Show>> {
+    events
+        .into_iter()
+        .map(|event| Box::new(event) as Box<dyn Show>)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        events: Events,
+    }
+
+    fn events(args: &[&str]) -> Events {
+        let args = ["events"].iter().chain(args);
+        Command::parse_from(args).events
+    }
+
+    fn event(name: &str, type_: &str, last_seen: &str) -> eventsv1::Event {
+        json::from_value(json::json!({
+            "metadata": {"name": name},
+            "type": type_,
+            "deprecatedLastTimestamp": last_seen,
+        }))
+        .unwrap()
+    }
+
+    fn names(events: &[eventsv1::Event]) -> Vec<String> {
+        events.iter().map(|event| event.name_any()).collect()
+    }
+
+    fn items() -> Vec<eventsv1::Event> {
+        vec![
+            event("pulled", "Normal", "2024-01-01T00:05:00Z"),
+            event("backoff", "Warning", "2024-01-01T00:10:00Z"),
+            event("scheduled", "Normal", "2024-01-01T00:00:00Z"),
+        ]
+    }
+
+    #[test]
+    fn sorted_by_last_seen() {
+        let events = events(&[]).select(items());
+        assert_eq!(names(&events), ["scheduled", "pulled", "backoff"]);
+    }
+
+    #[test]
+    fn types() {
+        let events_of = |args: &[&str]| names(&events(args).select(items()));
+        assert_eq!(events_of(&["--types=Warning"]), ["backoff"]);
+        assert_eq!(events_of(&["--types=normal"]), ["scheduled", "pulled"]);
+        assert_eq!(
+            events_of(&["--types=Normal,Warning"]),
+            ["scheduled", "pulled", "backoff"]
+        );
+    }
+
+    #[test]
+    fn regarding() {
+        let kubeapi = Kubeapi::local();
+        let regarding = |args: &[&str]| events(args).regarding(&kubeapi);
+
+        assert!(regarding(&[]).unwrap().is_none());
+        assert_eq!(
+            regarding(&["--for=pod/web"]).unwrap(),
+            Some(("Pod".to_string(), "web".to_string()))
+        );
+        assert_eq!(
+            regarding(&["--for=nodes/worker"]).unwrap(),
+            Some(("Node".to_string(), "worker".to_string()))
+        );
+        let err = regarding(&["--for=web"]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid --for 'web', use TYPE/NAME");
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Execute a command in a container.
+///
+///  The pod is picked by name, or as the first pod of a TYPE/NAME (e.g. deployment/web).
+/// The exit code of the remote command becomes the exit code of rkubectl.
+#[derive(Clone, Debug, Args)]
+pub struct Exec {
+    /// Container name. If omitted, use the kubectl.kubernetes.io/default-container annotation for selecting the
+    /// container to be attached or the first container in the pod will be chosen
+    #[arg(short, long)]
+    container: Option<String>,
+
+    /// Only print output from the remote session
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Pass stdin to the container
+    #[arg(short = 'i', long)]
+    stdin: bool,
+
+    /// Stdin is a TTY
+    #[arg(short, long)]
+    tty: bool,
+
+    /// Pod name or TYPE/NAME, e.g. deployment/web
+    #[arg(value_name = "POD | TYPE/NAME")]
+    target: String,
+
+    /// Command to execute, followed by its arguments
+    #[arg(last = true, required = true, value_name = "COMMAND")]
+    command: Vec<String>,
+}
+
+impl Exec {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let pod = target::pod(kubeapi, &self.target).await?;
+        let container = self
+            .container
+            .clone()
+            .or_else(|| target::default_container(&pod, self.quiet))
+            .ok_or_else(|| {
+                RkError::not_found(format!("No containers in pod {}", pod.name_any()))
+            })?;
+
+        let terminal = Terminal::new(self.stdin, self.tty, self.quiet);
+        let ap = terminal.attach_params(&container);
+        let namespace = pod.namespace().unwrap_or_default();
+        let process = kubeapi
+            .namespaced_pods(&namespace)?
+            .exec(&pod.name_any(), &self.command, &ap)
+            .await?;
+        match terminal.run(process).await? {
+            0 => Ok(()),
+            code => Err(RkError::RemoteExit(code)),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::io::IsTerminal;
+
+use clap::ArgAction;
+use futures_util::AsyncBufReadExt;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use futures_util::future;
+use futures_util::stream;
+use futures_util::stream::BoxStream;
+use owo_colors::AnsiColors;
+use owo_colors::OwoColorize;
+use rkubectl_args::Duration;
+
+use super::*;
+
+/// Print the logs for a container in a pod or specified resource.
+///
+///  If the pod has only one container, the container name is optional. Workloads and services (e.g. deployment/web)
+/// are resolved to their pods through their selectors. The logs of all the pods are printed one after the other, or
+/// interleaved as they come when following.
+#[derive(Clone, Debug, Args)]
+pub struct Logs {
+    /// Get all containers' logs in the pod(s).
+    #[arg(long)]
+    all_containers: bool,
+
+    /// Print the logs of this container
+    #[arg(short, long, conflicts_with = "all_containers")]
+    container: Option<String>,
+
+    /// Specify if the logs should be streamed.
+    #[arg(short, long)]
+    follow: bool,
+
+    /// Maximum number of concurrent logs to follow when following more than one stream.
+    #[arg(long, default_value_t = 5)]
+    max_log_requests: usize,
+
+    /// Prefix each log line with the log source (pod name and container name).
+    /// Defaults to true when there is more than one source.
+    #[arg(long, num_args = 0..=1, default_missing_value = "true", action = ArgAction::Set)]
+    prefix: Option<bool>,
+
+    /// If true, print the logs for the previous instance of the container in a pod if it exists.
+    #[arg(short, long)]
+    previous: bool,
+
+    /// Only return logs newer than a relative duration like 5s, 2m, or 3h.
+    #[arg(long, value_parser = Duration::value_parser())]
+    since: Option<Duration>,
+
+    /// Lines of recent log file to display. Defaults to -1 with no selector, showing all log lines
+    /// otherwise 10, if a selector is provided.
+    #[arg(long, allow_negative_numbers = true)]
+    tail: Option<i64>,
+
+    /// Include timestamps on each line in the log output
+    #[arg(long)]
+    timestamps: bool,
+
+    /// Pod name or TYPE/NAME, e.g. deployment/web
+    /// Required unless pods are selected with -l, --selector.
+    #[arg(value_name = "POD | TYPE/NAME")]
+    target: Option<String>,
+}
+
+#[derive(Debug)]
+struct LogSource {
+    namespace: String,
+    pod: String,
+    container: String,
+    color: AnsiColors,
+}
+
+impl LogSource {
+    fn prefix(&self, colored: bool) -> String {
+        let prefix = format!("[pod/{}/{}]", self.pod, self.container);
+        if colored {
+            prefix.color(self.color).to_string()
+        } else {
+            prefix
+        }
+    }
+}
+
+impl Logs {
+    const COLORS: [AnsiColors; 6] = [
+        AnsiColors::Cyan,
+        AnsiColors::Green,
+        AnsiColors::Magenta,
+        AnsiColors::Yellow,
+        AnsiColors::Blue,
+        AnsiColors::Red,
+    ];
+
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        // The selector is a global argument, out of sight for clap here
+        if self.target.is_none() && !kubeapi.has_label_selector() {
+            return Err(RkError::invalid_arguments(
+                "expected a POD or TYPE/NAME argument, or a selector with -l, --selector",
+            ));
+        }
+        let pods = self.pods(kubeapi).await?;
+        if pods.is_empty() {
+            return Err(RkError::not_found("No pods found"));
+        }
+
+        let sources = self.sources(&pods);
+        if self.follow && sources.len() > self.max_log_requests {
+            return Err(RkError::invalid_arguments(format!(
+                "you are attempting to follow {} log streams, but maximum allowed concurrency is {}, use --max-log-requests to increase the limit",
+                sources.len(),
+                self.max_log_requests
+            )));
+        }
+
+        let prefix = self.prefix.unwrap_or(sources.len() > 1);
+        let colored = io::stdout().is_terminal();
+        let streams = sources.iter().map(|source| {
+            let prefix = prefix.then(|| source.prefix(colored));
+            self.stream(kubeapi, source, prefix)
+        });
+        if self.follow {
+            let streams = future::try_join_all(streams).await?;
+            print_lines(stream::select_all(streams)).await
+        } else {
+            for stream in streams {
+                print_lines(stream.await?).await?;
+            }
+            Ok(())
+        }
+    }
+
+    async fn pods(&self, kubeapi: &Kubeapi) -> RkResult<Vec<corev1::Pod>> {
+        let Some(target) = self.target.as_deref() else {
+            let pods = kubeapi.pods()?.list(&kubeapi.list_params()).await?;
+            return Ok(pods.items);
+        };
+        target::pods(kubeapi, target).await
+    }
+
+    fn sources(&self, pods: &[corev1::Pod]) -> Vec<LogSource> {
+        pods.iter()
+            .enumerate()
+            .flat_map(|(index, pod)| {
+                let namespace = pod.namespace().unwrap_or_default();
+                let name = pod.name_any();
+                let color = Self::COLORS[index % Self::COLORS.len()];
+                self.containers(pod)
+                    .into_iter()
+                    .map(move |container| LogSource {
+                        namespace: namespace.clone(),
+                        pod: name.clone(),
+                        container,
+                        color,
+                    })
+            })
+            .collect()
+    }
+
+    fn containers(&self, pod: &corev1::Pod) -> Vec<String> {
+        if let Some(container) = self.container.as_ref() {
+            return vec![container.clone()];
+        }
+
+        if self.all_containers {
+            let spec = pod.spec.clone().unwrap_or_default();
+            let init = spec.init_containers.unwrap_or_default();
+            let ephemeral = spec.ephemeral_containers.unwrap_or_default();
+            return init
+                .into_iter()
+                .map(|container| container.name)
+                .chain(spec.containers.into_iter().map(|container| container.name))
+                .chain(ephemeral.into_iter().map(|container| container.name))
+                .collect();
+        }
+
+        target::default_container(pod, false).into_iter().collect()
+    }
+
+    async fn stream(
+        &self,
+        kubeapi: &Kubeapi,
+        source: &LogSource,
+        prefix: Option<String>,
+    ) -> kube::Result<BoxStream<'static, io::Result<String>>> {
+        let lp = self.log_params(&source.container);
+        let lines = kubeapi
+            .namespaced_pods(&source.namespace)?
+            .log_stream(&source.pod, &lp)
+            .await?
+            .lines()
+            .map_ok(move |line| match prefix.as_deref() {
+                Some(prefix) => format!("{prefix} {line}"),
+                None => line,
+            });
+        Ok(lines.boxed())
+    }
+
+    fn log_params(&self, container: &str) -> api::LogParams {
+        // Same as kubectl, a selector limits the output to the last 10 lines by default
+        let tail_lines = match self.tail {
+            Some(tail) if tail < 0 => None,
+            Some(tail) => Some(tail),
+            None if self.target.is_none() => Some(10),
+            None => None,
+        };
+        api::LogParams {
+            container: Some(container.to_string()),
+            follow: self.follow,
+            previous: self.previous,
+            since_seconds: self
+                .since
+                .map(|since| since.duration().as_secs() as i64)
+                .filter(|since| *since > 0),
+            tail_lines,
+            timestamps: self.timestamps,
+            ..default()
+        }
+    }
+}
+
+async fn print_lines(mut lines: impl Stream<Item = io::Result<String>> + Unpin) -> RkResult<()> {
+    while let Some(line) = lines.try_next().await? {
+        println!("{line}");
+    }
+    Ok(())
+}
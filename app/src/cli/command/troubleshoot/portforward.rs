@@ -0,0 +1,385 @@
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time;
+
+use futures_util::future;
+use k8s::intstr::IntOrString;
+use rkubectl_args::Duration;
+use rkubectl_args::PortMapping;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+
+use super::*;
+
+/// Forward one or more local ports to a pod.
+///
+///  Use resource type/name such as deployment/mydeployment to select a pod. Resource type defaults to 'pod' if omitted.
+/// If there are multiple pods matching the criteria, a ready pod will be selected automatically. When the pod goes
+/// away, the next connection is forwarded to another pod matching the criteria.
+#[derive(Clone, Debug, Args)]
+pub struct PortForward {
+    /// Addresses to listen on (comma separated). Only accepts IP addresses or localhost as a value.
+    /// When localhost is supplied, rkubectl will try to bind on both 127.0.0.1 and ::1 and will fail
+    /// if neither of these addresses are available to bind.
+    #[arg(long, value_delimiter = ',', default_value = "localhost")]
+    address: Vec<String>,
+
+    /// The length of time (like 5s, 2m, or 3h, higher than zero) to wait until at least one pod is running
+    #[arg(long, default_value = "1m", value_parser = Duration::value_parser())]
+    pod_running_timeout: Duration,
+
+    /// Pod name or TYPE/NAME, e.g. svc/db or deployment/web
+    #[arg(value_name = "POD | TYPE/NAME")]
+    target: String,
+
+    /// Port pairs to forward, [LOCAL:]REMOTE
+    #[arg(required = true, value_name = "[LOCAL:]REMOTE", value_parser = PortMapping::value_parser())]
+    ports: Vec<PortMapping>,
+}
+
+impl PortForward {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let forwarder = Arc::new(Forwarder::new(context.kubeapi().clone(), self.clone()).await?);
+        let addresses = self.addresses()?;
+        let backend = forwarder.backend.lock().await.clone();
+
+        let mut listeners = vec![];
+        for (index, mapping) in self.ports.iter().enumerate() {
+            let remote = backend.ports[index];
+            // Same as kubectl, the port asked for, e.g. 80 for a service whose target port is 8080
+            let mut local = mapping.local().unwrap_or(backend.requested[index]);
+            let mut bound = 0;
+            for (position, address) in addresses.iter().enumerate() {
+                match TcpListener::bind(SocketAddr::new(*address, local)).await {
+                    Ok(listener) => {
+                        // A random port is picked once, then reused for the remaining addresses
+                        local = listener.local_addr()?.port();
+                        println!("Forwarding from {} -> {remote}", listener.local_addr()?);
+                        listeners.push((index, listener));
+                        bound += 1;
+                    }
+                    // Same as kubectl, localhost is fine as long as one of IPv4 or IPv6 works
+                    Err(err) if self.is_localhost() && position + 1 < addresses.len() => {
+                        debug!(%address, local, %err, "Failed to listen");
+                    }
+                    Err(err) if self.is_localhost() && bound > 0 => {
+                        debug!(%address, local, %err, "Failed to listen");
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        let accepting = listeners.into_iter().map(|(index, listener)| {
+            let forwarder = forwarder.clone();
+            tokio::spawn(async move { forwarder.accept(index, listener).await })
+        });
+        let accepting = future::join_all(accepting);
+        tokio::select! {
+            _ = accepting => {}
+            _ = tokio::signal::ctrl_c() => {}
+        }
+        Ok(())
+    }
+
+    fn is_localhost(&self) -> bool {
+        self.address == ["localhost"]
+    }
+
+    fn addresses(&self) -> RkResult<Vec<IpAddr>> {
+        let mut addresses = vec![];
+        for address in &self.address {
+            if address == "localhost" {
+                addresses.push(IpAddr::V4(Ipv4Addr::LOCALHOST));
+                addresses.push(IpAddr::V6(Ipv6Addr::LOCALHOST));
+            } else {
+                let address = address.parse::<IpAddr>().map_err(|_| {
+                    RkError::invalid_arguments(format!("{address} is not a valid IP address"))
+                })?;
+                addresses.push(address);
+            }
+        }
+        Ok(addresses)
+    }
+}
+
+/// Pod currently backing the forwarded ports, along with the remote port for each mapping
+#[derive(Clone, Debug)]
+struct Backend {
+    namespace: String,
+    pod: String,
+    ports: Vec<u16>,
+    /// The port number each mapping asked for, the service port for services
+    requested: Vec<u16>,
+}
+
+#[derive(Debug)]
+struct Forwarder {
+    kubeapi: Kubeapi,
+    args: PortForward,
+    backend: Mutex<Backend>,
+}
+
+impl Forwarder {
+    const POLL_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+    async fn new(kubeapi: Kubeapi, args: PortForward) -> RkResult<Self> {
+        let backend = Self::resolve(&kubeapi, &args).await?;
+        let backend = Mutex::new(backend);
+        Ok(Self {
+            kubeapi,
+            args,
+            backend,
+        })
+    }
+
+    async fn accept(self: Arc<Self>, index: usize, listener: TcpListener) {
+        loop {
+            let connection = match listener.accept().await {
+                Ok((connection, _peer)) => connection,
+                Err(err) => {
+                    eprintln!("error accepting connection: {err}");
+                    continue;
+                }
+            };
+            println!(
+                "Handling connection for {}",
+                listener.local_addr().map_or(0, |addr| addr.port())
+            );
+            let forwarder = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = forwarder.forward(index, connection).await {
+                    eprintln!("error forwarding connection: {err}");
+                }
+            });
+        }
+    }
+
+    async fn forward(&self, index: usize, mut connection: TcpStream) -> RkResult<()> {
+        let (mut forwarder, port) = match self.portforward(index).await {
+            Ok(forwarder) => forwarder,
+            Err(err) => {
+                // Most likely the pod is gone, pick another one and try again
+                debug!(%err, "Port forwarding failed, reconnecting");
+                self.reconnect().await?;
+                self.portforward(index).await?
+            }
+        };
+        let mut upstream = forwarder
+            .take_stream(port)
+            .ok_or_else(|| io::Error::other(format!("no stream for port {port}")))?;
+        tokio::io::copy_bidirectional(&mut connection, &mut upstream).await?;
+        drop(upstream);
+        forwarder.join().await.map_err(io::Error::other)?;
+        Ok(())
+    }
+
+    async fn portforward(&self, index: usize) -> kube::Result<(api::Portforwarder, u16)> {
+        let backend = self.backend.lock().await.clone();
+        let port = backend.ports[index];
+        let forwarder = self
+            .kubeapi
+            .namespaced_pods(&backend.namespace)?
+            .portforward(&backend.pod, &[port])
+            .await?;
+        Ok((forwarder, port))
+    }
+
+    async fn reconnect(&self) -> RkResult<()> {
+        let backend = Self::resolve(&self.kubeapi, &self.args).await?;
+        eprintln!("Forwarding to pod {}", backend.pod);
+        *self.backend.lock().await = backend;
+        Ok(())
+    }
+
+    /// Waits for a running (preferably ready) pod behind the target,
+    /// and maps the requested remote ports onto its container ports
+    async fn resolve(kubeapi: &Kubeapi, args: &PortForward) -> RkResult<Backend> {
+        let service = service(kubeapi, &args.target).await?;
+        let deadline = time::Instant::now() + args.pod_running_timeout.duration();
+        let pod = loop {
+            let pods = target::pods(kubeapi, &args.target).await?;
+            if let Some(pod) = pick(pods) {
+                break pod;
+            }
+            if time::Instant::now() >= deadline {
+                return Err(RkError::not_found(format!(
+                    "timed out waiting for a running pod for {}",
+                    args.target
+                )));
+            }
+            tokio::time::sleep(Self::POLL_INTERVAL).await;
+        };
+
+        let (requested, ports) = args
+            .ports
+            .iter()
+            .map(|mapping| match service.as_ref() {
+                Some(service) => {
+                    let service_port = service_port(service, mapping.remote())?;
+                    Ok((
+                        port_number(service_port.port)?,
+                        target_port(&pod, service_port)?,
+                    ))
+                }
+                None => {
+                    let port = container_port(&pod, mapping.remote())?;
+                    Ok((port, port))
+                }
+            })
+            .collect::<RkResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        Ok(Backend {
+            namespace: pod.namespace().unwrap_or_default(),
+            pod: pod.name_any(),
+            ports,
+            requested,
+        })
+    }
+}
+
+/// The service itself when the target is one, as its ports need to be mapped to the target ports
+async fn service(kubeapi: &Kubeapi, target: &str) -> RkResult<Option<corev1::Service>> {
+    let Some((kind, name)) = target.split_once('/') else {
+        return Ok(None);
+    };
+    if matches!(kind, "svc" | "service" | "services") {
+        let service = kubeapi.services()?.get(name).await?;
+        Ok(Some(service))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Ready pods first, then the running ones
+fn pick(pods: Vec<corev1::Pod>) -> Option<corev1::Pod> {
+    let running = |pod: &corev1::Pod| {
+        pod.metadata.deletion_timestamp.is_none()
+            && pod
+                .status
+                .as_ref()
+                .and_then(|status| status.phase.as_deref())
+                == Some("Running")
+    };
+    let ready = |pod: &corev1::Pod| {
+        pod.status
+            .iter()
+            .flat_map(|status| status.conditions.iter().flatten())
+            .any(|condition| condition.type_ == "Ready" && condition.status == "True")
+    };
+    let (ready, running): (Vec<_>, Vec<_>) = pods.into_iter().filter(running).partition(ready);
+    ready.into_iter().chain(running).next()
+}
+
+fn port_number(port: i32) -> RkResult<u16> {
+    u16::try_from(port).map_err(|_| RkError::invalid_arguments(format!("invalid port {port}")))
+}
+
+fn container_port(pod: &corev1::Pod, port: &IntOrString) -> RkResult<u16> {
+    match port {
+        IntOrString::Int(port) => port_number(*port),
+        IntOrString::String(name) => pod
+            .spec
+            .iter()
+            .flat_map(|spec| spec.containers.iter())
+            .flat_map(|container| container.ports.iter().flatten())
+            .find(|port| port.name.as_deref() == Some(name))
+            .and_then(|port| u16::try_from(port.container_port).ok())
+            .ok_or_else(|| {
+                RkError::not_found(format!(
+                    "Pod '{}' does not have a named port '{name}'",
+                    pod.name_any()
+                ))
+            }),
+    }
+}
+
+/// The service port asked for, by number or by name
+fn service_port<'a>(
+    service: &'a corev1::Service,
+    port: &IntOrString,
+) -> RkResult<&'a corev1::ServicePort> {
+    service
+        .spec
+        .iter()
+        .flat_map(|spec| spec.ports.iter().flatten())
+        .find(|service_port| match port {
+            IntOrString::Int(port) => service_port.port == *port,
+            IntOrString::String(name) => service_port.name.as_deref() == Some(name),
+        })
+        .ok_or_else(|| {
+            RkError::not_found(format!(
+                "Service {} does not have a service port {}",
+                service.name_any(),
+                match port {
+                    IntOrString::Int(port) => port.to_string(),
+                    IntOrString::String(name) => name.clone(),
+                }
+            ))
+        })
+}
+
+/// The container port of `pod` the service port sends traffic to
+fn target_port(pod: &corev1::Pod, service_port: &corev1::ServicePort) -> RkResult<u16> {
+    match service_port.target_port.as_ref() {
+        Some(target) => container_port(pod, target),
+        None => port_number(service_port.port),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> corev1::Service {
+        json::from_value(json::json!({
+            "metadata": {"name": "web"},
+            "spec": {"ports": [
+                {"name": "http", "port": 80, "targetPort": "web"},
+                {"name": "metrics", "port": 9090, "targetPort": 9091},
+                {"name": "admin", "port": 8000},
+            ]},
+        }))
+        .unwrap()
+    }
+
+    fn pod() -> corev1::Pod {
+        json::from_value(json::json!({
+            "metadata": {"name": "web-1"},
+            "spec": {"containers": [{
+                "name": "web",
+                "ports": [{"name": "web", "containerPort": 8080}],
+            }]},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn service_ports() {
+        let service = service();
+        let http = service_port(&service, &IntOrString::Int(80)).unwrap();
+        assert_eq!(http.name.as_deref(), Some("http"));
+        let metrics = service_port(&service, &IntOrString::String("metrics".to_string())).unwrap();
+        assert_eq!(metrics.port, 9090);
+        assert!(service_port(&service, &IntOrString::Int(8080)).is_err());
+    }
+
+    #[test]
+    fn target_ports() {
+        let (service, pod) = (service(), pod());
+        let target = |port| target_port(&pod, service_port(&service, &IntOrString::Int(port))?);
+        // Named target port, looked up in the containers of the pod
+        assert_eq!(target(80).unwrap(), 8080);
+        assert_eq!(target(9090).unwrap(), 9091);
+        // Defaults to the service port
+        assert_eq!(target(8000).unwrap(), 8000);
+
+        let missing = IntOrString::String("grpc".to_string());
+        assert!(container_port(&pod, &missing).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use http_body_util::combinators::UnsyncBoxBody;
+use hyper::body::Bytes;
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use regex::Regex;
+use tokio::net::TcpListener;
+
+use super::*;
+
+type ProxyBody = UnsyncBoxBody<Bytes, Box<dyn StdError + Send + Sync>>;
+
+/// Creates a proxy server or application-level gateway between localhost and the Kubernetes API server.
+///
+/// It also allows serving static content over specified HTTP path.
+/// All incoming data enters through one port and gets forwarded to the remote Kubernetes API server port,
+/// except for the path matching the static content path.
+#[derive(Clone, Debug, Args)]
+pub struct Proxy {
+    /// The port on which to run the proxy. Set to 0 to pick a random port.
+    #[arg(short, long, default_value_t = 8001)]
+    port: u16,
+
+    /// The IP address on which to serve on.
+    #[arg(long, default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
+    address: IpAddr,
+
+    /// Prefix to serve the proxied API under.
+    #[arg(long, default_value = "/")]
+    api_prefix: String,
+
+    /// Regular expression for paths that the proxy should accept.
+    #[arg(long, value_delimiter = ',', default_value = "^.*")]
+    accept_paths: Vec<String>,
+
+    /// Regular expression for paths that the proxy should reject. Paths specified here will be rejected
+    /// even accepted by --accept-paths.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "^/api/.*/pods/.*/exec,^/api/.*/pods/.*/attach"
+    )]
+    reject_paths: Vec<String>,
+
+    /// Regular expression for hosts that the proxy should accept.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = r"^localhost$,^127\.0\.0\.1$,^\[::1\]$"
+    )]
+    accept_hosts: Vec<String>,
+
+    /// Also serve static files from the given directory under the specified prefix.
+    #[arg(short, long)]
+    www: Option<PathBuf>,
+
+    /// Prefix to serve static files under, if static file directory is specified.
+    #[arg(short = 'P', long, default_value = "/static/")]
+    www_prefix: String,
+}
+
+impl Proxy {
+    pub async fn exec(&self, context: &Context) -> RkResult<()> {
+        let server = Arc::new(Server {
+            client: context.kubeapi().client()?,
+            filter: Filter::new(&self.accept_paths, &self.reject_paths, &self.accept_hosts)?,
+            api_prefix: self.api_prefix.trim_end_matches('/').to_string(),
+            www: self.www.clone(),
+            www_prefix: self.www_prefix.clone(),
+        });
+
+        let listener = TcpListener::bind((self.address, self.port)).await?;
+        println!("Starting to serve on {}", listener.local_addr()?);
+
+        loop {
+            let (stream, peer) = tokio::select! {
+                accepted = listener.accept() => accepted?,
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            };
+            let server = server.clone();
+            tokio::spawn(async move {
+                let service = service_fn(|request| server.clone().handle(request));
+                if let Err(err) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!(%peer, %err, "Connection failed");
+                }
+            });
+        }
+    }
+}
+
+struct Server {
+    client: kube::Client,
+    filter: Filter,
+    api_prefix: String,
+    www: Option<PathBuf>,
+    www_prefix: String,
+}
+
+impl Server {
+    async fn handle(
+        self: Arc<Self>,
+        request: Request<Incoming>,
+    ) -> Result<Response<ProxyBody>, Infallible> {
+        let path = request.uri().path();
+        trace!(method = %request.method(), path, "Proxy request");
+
+        if let Some(www) = self.www.as_deref()
+            && let Some(file) = path.strip_prefix(&self.www_prefix)
+        {
+            return Ok(static_file(www, file).await);
+        }
+
+        let path = match path.strip_prefix(&self.api_prefix) {
+            Some("") => "/".to_string(),
+            Some(path) if path.starts_with('/') => path.to_string(),
+            _ => return Ok(text(StatusCode::NOT_FOUND, "404 page not found\n")),
+        };
+        let host = request
+            .headers()
+            .get(header::HOST)
+            .and_then(|host| host.to_str().ok())
+            .unwrap_or_default();
+        if !self.filter.accept(&path, host) {
+            return Ok(text(StatusCode::FORBIDDEN, "Forbidden\n"));
+        }
+
+        match self.forward(&path, request).await {
+            Ok(response) => Ok(response),
+            Err(err) => {
+                eprintln!("Error while proxying request: {err}");
+                Ok(text(StatusCode::BAD_GATEWAY, err.to_string()))
+            }
+        }
+    }
+
+    async fn forward(
+        &self,
+        path: &str,
+        request: Request<Incoming>,
+    ) -> RkResult<Response<ProxyBody>> {
+        let (parts, body) = request.into_parts();
+        let uri = match parts.uri.query() {
+            Some(query) => format!("{path}?{query}"),
+            None => path.to_string(),
+        };
+        let body = body.collect().await.map_err(io::Error::other)?.to_bytes();
+
+        let mut request = Request::builder()
+            .method(parts.method)
+            .uri(uri)
+            .body(kube::client::Body::from(body))
+            .map_err(|err| RkError::invalid_arguments(err.to_string()))?;
+        // The client supplies its own host and credentials
+        let headers = parts
+            .headers
+            .into_iter()
+            .filter_map(|(name, value)| name.map(|name| (name, value)))
+            .filter(|(name, _)| !matches!(*name, header::HOST | header::AUTHORIZATION));
+        request.headers_mut().extend(headers);
+
+        let response = self.client.send(request).await?;
+        Ok(response.map(|body| body.map_err(Into::into).boxed_unsync()))
+    }
+}
+
+/// Path and host filtering, mirrors `kubectl proxy` semantics
+#[derive(Debug)]
+struct Filter {
+    accept_paths: Vec<Regex>,
+    reject_paths: Vec<Regex>,
+    accept_hosts: Vec<Regex>,
+}
+
+impl Filter {
+    fn new(
+        accept_paths: &[String],
+        reject_paths: &[String],
+        accept_hosts: &[String],
+    ) -> RkResult<Self> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .filter(|pattern| !pattern.is_empty())
+                .map(|pattern| {
+                    Regex::new(pattern).map_err(|err| {
+                        RkError::invalid_arguments(format!("invalid regular expression: {err}"))
+                    })
+                })
+                .collect::<RkResult<Vec<_>>>()
+        };
+        Ok(Self {
+            accept_paths: compile(accept_paths)?,
+            reject_paths: compile(reject_paths)?,
+            accept_hosts: compile(accept_hosts)?,
+        })
+    }
+
+    fn accept(&self, path: &str, host: &str) -> bool {
+        let matches = |regexes: &[Regex], text: &str| regexes.iter().any(|re| re.is_match(text));
+        matches(&self.accept_hosts, hostname(host))
+            && matches(&self.accept_paths, path)
+            && !matches(&self.reject_paths, path)
+    }
+}
+
+/// Host header without the port, IPv6 addresses keep their brackets
+fn hostname(host: &str) -> &str {
+    if host.starts_with('[') {
+        host.find(']').map_or(host, |end| &host[..=end])
+    } else {
+        host.split_once(':')
+            .map_or(host, |(hostname, _port)| hostname)
+    }
+}
+
+async fn static_file(root: &Path, path: &str) -> Response<ProxyBody> {
+    let mut file = root.to_path_buf();
+    for component in path.split('/').filter(|component| !component.is_empty()) {
+        if component == "." || component == ".." {
+            return text(StatusCode::FORBIDDEN, "Forbidden\n");
+        }
+        file.push(component);
+    }
+    if file.is_dir() {
+        file.push("index.html");
+    }
+
+    match tokio::fs::read(&file).await {
+        Ok(content) => Response::builder()
+            .header(header::CONTENT_TYPE, content_type(&file))
+            .body(full(content))
+            .unwrap_or_else(|_| text(StatusCode::INTERNAL_SERVER_ERROR, "")),
+        Err(_) => text(StatusCode::NOT_FOUND, "404 page not found\n"),
+    }
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("html" | "htm") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js" | "mjs") => "text/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("ico") => "image/x-icon",
+        Some("txt") => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+fn text(status: StatusCode, text: impl Into<Bytes>) -> Response<ProxyBody> {
+    let mut response = Response::new(full(text));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    response
+}
+
+fn full(content: impl Into<Bytes>) -> ProxyBody {
+    Full::new(content.into())
+        .map_err(|never| match never {})
+        .boxed_unsync()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter() -> Filter {
+        let strings = |text: &str| text.split(',').map(String::from).collect::<Vec<_>>();
+        Filter::new(
+            &strings("^.*"),
+            &strings("^/api/.*/pods/.*/exec,^/api/.*/pods/.*/attach"),
+            &strings(r"^localhost$,^127\.0\.0\.1$,^\[::1\]$"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn default_filter() {
+        let filter = filter();
+        assert!(filter.accept("/api/v1/namespaces", "localhost:8001"));
+        assert!(filter.accept("/apis/apps/v1/deployments", "127.0.0.1:8001"));
+        assert!(filter.accept("/version", "[::1]:8001"));
+        assert!(!filter.accept("/api/v1/namespaces/default/pods/web/exec", "localhost"));
+        assert!(!filter.accept("/api/v1/namespaces/default/pods/web/attach", "localhost"));
+        assert!(!filter.accept("/api/v1/namespaces", "evil.example.com"));
+    }
+}
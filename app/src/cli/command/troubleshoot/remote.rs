@@ -0,0 +1,312 @@
+use std::io::IsTerminal;
+use std::io::Read;
+use std::thread;
+
+use futures_util::SinkExt;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use futures_util::future;
+use futures_util::stream;
+use kube::api::AttachedProcess;
+use kube::api::TerminalSize;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+
+use super::*;
+
+const STDIN_BUFFER: usize = 8 * 1024;
+
+/// Local end of a process started by `exec` or `attach`: wires the remote streams
+/// to the local stdio, handles the TTY and reports the remote exit code.
+#[derive(Debug)]
+pub(super) struct Terminal {
+    stdin: bool,
+    tty: bool,
+}
+
+impl Terminal {
+    /// A TTY is only allocated when the local stdin is a terminal too, as kubectl does
+    pub(super) fn new(stdin: bool, tty: bool, quiet: bool) -> Self {
+        let tty = if tty && !(stdin && io::stdin().is_terminal()) {
+            if !quiet {
+                eprintln!(
+                    "Unable to use a TTY - input is not a terminal or the right kind of file"
+                );
+            }
+            false
+        } else {
+            tty
+        };
+        Self { stdin, tty }
+    }
+
+    pub(super) fn tty(&self) -> bool {
+        self.tty
+    }
+
+    /// Remote streams to request, stderr is merged into stdout by a TTY
+    pub(super) fn attach_params(&self, container: &str) -> api::AttachParams {
+        api::AttachParams::default()
+            .container(container)
+            .stdin(self.stdin)
+            .stdout(true)
+            .stderr(!self.tty)
+            .tty(self.tty)
+    }
+
+    /// Runs `process` to completion and returns its exit code
+    pub(super) async fn run(&self, mut process: AttachedProcess) -> RkResult<i32> {
+        let _raw = self.tty.then(RawMode::enable).flatten();
+        if let Some(mut sender) = process.terminal_size() {
+            tokio::spawn(async move {
+                let mut sizes = window_sizes().boxed();
+                while let Some(size) = sizes.next().await {
+                    if sender.send(size).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        let input = self.stdin.then(stdin);
+        pipe(process, input, tokio::io::stdout(), tokio::io::stderr()).await
+    }
+}
+
+/// Copies `input` to the remote stdin, and the remote stdout and stderr to `output` and `error`
+/// until the remote side is done, then turns the status sent over the status channel into
+/// an exit code.
+async fn pipe<I, O, E>(
+    mut process: AttachedProcess,
+    input: Option<I>,
+    mut output: O,
+    mut error: E,
+) -> RkResult<i32>
+where
+    I: AsyncRead + Unpin + Send + 'static,
+    O: AsyncWrite + Unpin,
+    E: AsyncWrite + Unpin,
+{
+    let status = process.take_status();
+    if let (Some(mut input), Some(mut stdin)) = (input, process.stdin()) {
+        tokio::spawn(async move {
+            // Closing the remote stdin once the local one is exhausted lets the process finish
+            let _ = tokio::io::copy(&mut input, &mut stdin).await;
+            let _ = stdin.shutdown().await;
+        });
+    }
+
+    let stdout = process.stdout();
+    let stderr = process.stderr();
+    let stdout = async {
+        if let Some(mut stdout) = stdout {
+            tokio::io::copy(&mut stdout, &mut output).await?;
+            output.flush().await?;
+        }
+        io::Result::Ok(())
+    };
+    let stderr = async {
+        if let Some(mut stderr) = stderr {
+            tokio::io::copy(&mut stderr, &mut error).await?;
+            error.flush().await?;
+        }
+        io::Result::Ok(())
+    };
+    tokio::try_join!(stdout, stderr)?;
+
+    let status = match status {
+        Some(status) => status.await,
+        None => None,
+    };
+    process.join().await.map_err(io::Error::other)?;
+    Ok(exit_code(status.as_ref()))
+}
+
+/// `Success` is 0, a failed command reports its exit code in the `ExitCode` cause,
+/// anything else (e.g. the container is gone) is reported as a plain failure.
+pub(super) fn exit_code(status: Option<&metav1::Status>) -> i32 {
+    let Some(status) = status else {
+        return 0;
+    };
+    if status.status.as_deref() == Some("Success") {
+        return 0;
+    }
+    let code = status
+        .details
+        .iter()
+        .flat_map(|details| details.causes.iter().flatten())
+        .find(|cause| cause.reason.as_deref() == Some("ExitCode"))
+        .and_then(|cause| cause.message.as_deref()?.parse().ok());
+    match code {
+        Some(code) => code,
+        None => {
+            if let Some(message) = status.message.as_deref() {
+                eprintln!("{message}");
+            }
+            1
+        }
+    }
+}
+
+/// Local stdin, read on a plain thread rather than with `tokio::io::stdin()`,
+/// so that a pending read does not hold the runtime back when the remote process exits
+fn stdin() -> tokio::io::DuplexStream {
+    let (mut writer, reader) = tokio::io::duplex(STDIN_BUFFER);
+    let handle = tokio::runtime::Handle::current();
+    thread::spawn(move || {
+        let mut stdin = io::stdin().lock();
+        let mut buffer = [0; STDIN_BUFFER];
+        loop {
+            match stdin.read(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(size) => {
+                    if handle.block_on(writer.write_all(&buffer[..size])).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        let _ = handle.block_on(writer.shutdown());
+    });
+    reader
+}
+
+fn terminal_size() -> Option<TerminalSize> {
+    terminal_size::terminal_size().map(|(width, height)| TerminalSize {
+        width: width.0,
+        height: height.0,
+    })
+}
+
+/// Current terminal size, followed by the new one every time the window is resized
+#[cfg(unix)]
+fn window_sizes() -> impl Stream<Item = TerminalSize> {
+    use tokio::signal::unix::SignalKind;
+    use tokio::signal::unix::signal;
+
+    let signal = signal(SignalKind::window_change()).ok();
+    let changes = stream::unfold(signal, |signal| async move {
+        let mut signal = signal?;
+        signal.recv().await?;
+        Some((terminal_size(), Some(signal)))
+    });
+    stream::iter(terminal_size()).chain(changes.filter_map(future::ready))
+}
+
+#[cfg(not(unix))]
+fn window_sizes() -> impl Stream<Item = TerminalSize> {
+    stream::iter(terminal_size())
+}
+
+/// Puts the local terminal in raw mode, so that keystrokes (including ^C) go to the
+/// remote TTY as they are typed; the original mode is restored on drop.
+struct RawMode {
+    #[cfg(unix)]
+    saved: rustix::termios::Termios,
+}
+
+impl RawMode {
+    #[cfg(unix)]
+    fn enable() -> Option<Self> {
+        use rustix::termios;
+
+        let stdin = io::stdin();
+        let saved = termios::tcgetattr(&stdin).ok()?;
+        let mut raw = saved.clone();
+        raw.make_raw();
+        termios::tcsetattr(&stdin, termios::OptionalActions::Now, &raw).ok()?;
+        Some(Self { saved })
+    }
+
+    #[cfg(not(unix))]
+    fn enable() -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        use rustix::termios;
+
+        let _ = termios::tcsetattr(io::stdin(), termios::OptionalActions::Now, &self.saved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::SinkExt;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::handshake::server;
+    use tokio_tungstenite::tungstenite::http::HeaderValue;
+    use tokio_tungstenite::tungstenite::http::header;
+
+    use super::*;
+
+    const STDOUT: u8 = 1;
+    const STDERR: u8 = 2;
+    const STATUS: u8 = 3;
+
+    fn frame(channel: u8, data: &[u8]) -> Message {
+        let frame = iter::once(channel)
+            .chain(data.iter().copied())
+            .collect::<Vec<_>>();
+        Message::binary(frame)
+    }
+
+    /// Stand-in for the API server side of `pods/exec`, speaking `v5.channel.k8s.io`
+    async fn stand_in(frames: Vec<Message>) -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            // The handshake callback signature is dictated by tungstenite
+            #[expect(clippy::result_large_err)]
+            let callback = |_request: &server::Request, mut response: server::Response| {
+                let protocol = HeaderValue::from_static("v5.channel.k8s.io");
+                response
+                    .headers_mut()
+                    .insert(header::SEC_WEBSOCKET_PROTOCOL, protocol);
+                Ok(response)
+            };
+            let mut ws = tokio_tungstenite::accept_hdr_async(stream, callback)
+                .await
+                .unwrap();
+            for frame in frames {
+                ws.send(frame).await.unwrap();
+            }
+            ws.close(None).await.unwrap();
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn exit_code_from_status_channel() {
+        let status = json::json!({
+            "status": "Failure",
+            "reason": "NonZeroExitCode",
+            "details": { "causes": [{ "reason": "ExitCode", "message": "3" }] }
+        });
+        let frames = vec![
+            frame(STDOUT, b"hello\n"),
+            frame(STDERR, b"oops\n"),
+            frame(STATUS, status.to_string().as_bytes()),
+        ];
+        let addr = stand_in(frames).await;
+
+        let config = kube::Config::new(format!("http://{addr}").parse().unwrap());
+        let client = kube::Client::try_from(config).unwrap();
+        let pods = api::Api::<corev1::Pod>::namespaced(client, "default");
+        let ap = api::AttachParams::default().stdout(true).stderr(true);
+        let process = pods.exec("pod", ["false"], &ap).await.unwrap();
+
+        let (mut output, mut error) = (vec![], vec![]);
+        let code = pipe(process, None::<tokio::io::Empty>, &mut output, &mut error)
+            .await
+            .unwrap();
+        assert_eq!(code, 3);
+        assert_eq!(output, b"hello\n");
+        assert_eq!(error, b"oops\n");
+    }
+}
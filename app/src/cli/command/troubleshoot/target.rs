@@ -0,0 +1,51 @@
+use super::*;
+
+const DEFAULT_CONTAINER_ANNOTATION: &str = "kubectl.kubernetes.io/default-container";
+
+/// Pods referred to by `POD` or `TYPE/NAME` on the command line,
+/// workloads and services are resolved through their selectors.
+pub(super) async fn pods(kubeapi: &Kubeapi, target: &str) -> RkResult<Vec<corev1::Pod>> {
+    if !target.contains('/') {
+        let pod = kubeapi.pods()?.get(target).await?;
+        return Ok(vec![pod]);
+    }
+    let resources = ResourceArg::from_strings(&[target.to_string()], kubeapi)
+        .map_err(|_err| RkError::invalid_arguments(format!("invalid resource '{target}'")))?;
+    let Some(ResourceArg::NamedResource(resource)) = resources.first() else {
+        return Err(RkError::invalid_arguments(format!(
+            "invalid resource '{target}'"
+        )));
+    };
+    Ok(resource.pods(kubeapi).await?)
+}
+
+/// Same as `pods()`, but picks the first one, like kubectl does for `exec` and `attach`
+pub(super) async fn pod(kubeapi: &Kubeapi, target: &str) -> RkResult<corev1::Pod> {
+    pods(kubeapi, target)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| RkError::not_found(format!("No pods found for {target}")))
+}
+
+/// Container named by the `kubectl.kubernetes.io/default-container` annotation,
+/// or the first one, letting the user know when there was a choice to make (unless `quiet`)
+pub(super) fn default_container(pod: &corev1::Pod, quiet: bool) -> Option<String> {
+    if let Some(container) = pod.annotations().get(DEFAULT_CONTAINER_ANNOTATION) {
+        return Some(container.clone());
+    }
+    let names = pod
+        .spec
+        .iter()
+        .flat_map(|spec| spec.containers.iter())
+        .map(|container| container.name.clone())
+        .collect::<Vec<_>>();
+    if names.len() > 1 && !quiet {
+        eprintln!(
+            "Defaulted container \"{}\" out of: {}",
+            names[0],
+            names.join(", ")
+        );
+    }
+    names.into_iter().next()
+}
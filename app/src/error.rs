@@ -6,6 +6,31 @@ pub enum RkError {
     Io(#[from] io::Error),
     #[error(transparent)]
     Kube(#[from] kube::Error),
+    #[error(transparent)]
+    Manifest(#[from] rkubectl_manifest::ManifestError),
+    #[error("{0}")]
+    #[diagnostic(help("See --help for the supported arguments"))]
+    InvalidArguments(String),
+    #[error("{0}")]
+    #[diagnostic(help("Check the resource names, the namespace and the selectors"))]
+    NotFound(String),
+    #[error("{0}")]
+    #[diagnostic(help(
+        "Use --force-conflicts to take ownership of these fields, or remove them from the manifest"
+    ))]
+    ApplyConflict(String),
+    #[error("{0}")]
+    #[diagnostic(help("Use a longer --timeout, or check what holds the objects back"))]
+    Timeout(String),
+    #[error("{0}")]
+    #[diagnostic(help(
+        "Check $KUBE_EDITOR or $EDITOR, and fix the errors embedded in the edited file"
+    ))]
+    Edit(String),
+    #[error("command terminated with exit code {0}")]
+    RemoteExit(i32),
+    #[error("exit status {0}")]
+    Exit(i32),
     #[error("Not implemented yet")]
     #[diagnostic(help("This functionality is not implemented yet"))]
     NotImplemented,
@@ -15,4 +40,21 @@ impl RkError {
     pub fn todo() -> Self {
         Self::NotImplemented
     }
+
+    pub fn invalid_arguments(text: impl ToString) -> Self {
+        Self::InvalidArguments(text.to_string())
+    }
+
+    pub fn not_found(text: impl ToString) -> Self {
+        Self::NotFound(text.to_string())
+    }
+}
+
+impl From<SelectError> for RkError {
+    fn from(err: SelectError) -> Self {
+        match err {
+            SelectError::NoName => Self::invalid_arguments(err),
+            SelectError::Kube(err) => Self::Kube(err),
+        }
+    }
 }
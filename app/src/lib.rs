@@ -15,8 +15,11 @@ use tracing::debug;
 use tracing::info;
 use tracing::trace;
 
+use k8s::appsv1;
 use k8s::authenticationv1;
 use k8s::authorizationv1;
+use k8s::autoscalingv1;
+use k8s::autoscalingv2;
 use k8s::corev1;
 use k8s::metav1;
 use k8s::rbacv1;
@@ -30,6 +33,7 @@ use rkubectl_kubeapi::KubeapiOptions;
 use rkubectl_kubeapi::Namespace;
 use rkubectl_resource::InvalidResourceSpec;
 use rkubectl_resource::ResourceArg;
+use rkubectl_resource::SelectError;
 use rkubectl_ui::OutputFormat;
 use rkubectl_ui::Show;
 use rkubectl_ui::ShowParams;
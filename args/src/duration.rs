@@ -0,0 +1,105 @@
+use std::fmt;
+use std::time;
+
+use super::*;
+
+/// Go style duration as accepted by kubectl flags, e.g. `30s`, `5m` or `1h30m`.
+///
+/// A bare `0` is accepted as well, fractions are allowed (`1.5h`),
+/// and units are `ns`, `us` (or `µs`), `ms`, `s`, `m` and `h`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration(time::Duration);
+
+impl Duration {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    pub fn duration(&self) -> time::Duration {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text == "0" {
+            return Ok(Self::default());
+        }
+        if text.is_empty() {
+            return Err("invalid duration: empty string".to_string());
+        }
+
+        let mut rest = text;
+        let mut seconds = 0.0;
+        while !rest.is_empty() {
+            let split = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let (number, tail) = rest.split_at(split);
+            let number = number
+                .parse::<f64>()
+                .map_err(|_| format!("invalid duration '{text}'"))?;
+            let split = tail
+                .find(|c: char| c.is_ascii_digit() || c == '.')
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(split);
+            let scale = match unit {
+                "ns" => 1e-9,
+                "us" | "µs" => 1e-6,
+                "ms" => 1e-3,
+                "s" => 1.0,
+                "m" => 60.0,
+                "h" => 3600.0,
+                "" => return Err(format!("missing unit in duration '{text}'")),
+                unit => return Err(format!("unknown unit '{unit}' in duration '{text}'")),
+            };
+            seconds += number * scale;
+            rest = tail;
+        }
+
+        Ok(Self(time::Duration::from_secs_f64(seconds)))
+    }
+}
+
+impl From<time::Duration> for Duration {
+    fn from(duration: time::Duration) -> Self {
+        Self(duration)
+    }
+}
+
+// Same as Go's `time.Duration.String()` for whole seconds, e.g. `1h30m0s`
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.0.as_secs();
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        if hours > 0 {
+            write!(f, "{hours}h{minutes}m{seconds}s")
+        } else if minutes > 0 {
+            write!(f, "{minutes}m{seconds}s")
+        } else {
+            write!(f, "{seconds}s")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn go_durations() {
+        let secs = |text| Duration::from_text(text).map(|duration| duration.0.as_secs_f64());
+        assert_eq!(secs("0"), Ok(0.0));
+        assert_eq!(secs("30s"), Ok(30.0));
+        assert_eq!(secs("5m"), Ok(300.0));
+        assert_eq!(secs("1h30m"), Ok(5400.0));
+        assert_eq!(secs("1.5h"), Ok(5400.0));
+        assert_eq!(secs("250ms"), Ok(0.25));
+        assert!(secs("10").is_err());
+        assert!(secs("10d").is_err());
+        assert!(secs("").is_err());
+    }
+}
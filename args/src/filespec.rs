@@ -0,0 +1,105 @@
+use std::fmt;
+
+use super::*;
+
+/// Source or destination of `cp`, `[[NAMESPACE/]POD:]PATH`.
+///
+/// Anything without a colon, or starting with `/` or `.`, is a local path,
+/// so that local files with a colon in their name can still be referred to as `./a:b`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileSpec {
+    Local(PathBuf),
+    Remote {
+        namespace: Option<String>,
+        pod: String,
+        path: String,
+    },
+}
+
+impl FileSpec {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    pub fn is_remote(&self) -> bool {
+        matches!(self, Self::Remote { .. })
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        if text.starts_with(['/', '.']) {
+            return Ok(Self::Local(PathBuf::from(text)));
+        }
+        let Some((pod, path)) = text.split_once(':') else {
+            return Ok(Self::Local(PathBuf::from(text)));
+        };
+        let (namespace, pod) = match pod.split_once('/') {
+            Some((namespace, pod)) => (Some(namespace.to_string()), pod.to_string()),
+            None => (None, pod.to_string()),
+        };
+        if pod.is_empty() || pod.contains('/') || namespace.as_deref() == Some("") {
+            return Err(format!(
+                "'{text}' must match the canonical format: [[namespace/]pod:]file/path"
+            ));
+        }
+        if path.is_empty() {
+            return Err(format!("remote path cannot be empty in '{text}'"));
+        }
+        let path = path.to_string();
+        Ok(Self::Remote {
+            namespace,
+            pod,
+            path,
+        })
+    }
+}
+
+impl fmt::Display for FileSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local(path) => path.display().fmt(f),
+            Self::Remote {
+                namespace: Some(namespace),
+                pod,
+                path,
+            } => write!(f, "{namespace}/{pod}:{path}"),
+            Self::Remote {
+                namespace: None,
+                pod,
+                path,
+            } => write!(f, "{pod}:{path}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_specs() {
+        let remote = |namespace: Option<&str>, pod: &str, path: &str| FileSpec::Remote {
+            namespace: namespace.map(String::from),
+            pod: pod.to_string(),
+            path: path.to_string(),
+        };
+        assert_eq!(
+            FileSpec::from_text("web:/etc/nginx"),
+            Ok(remote(None, "web", "/etc/nginx"))
+        );
+        assert_eq!(
+            FileSpec::from_text("prod/web:logs"),
+            Ok(remote(Some("prod"), "web", "logs"))
+        );
+        assert_eq!(
+            FileSpec::from_text("data/out.txt"),
+            Ok(FileSpec::Local(PathBuf::from("data/out.txt")))
+        );
+        assert_eq!(
+            FileSpec::from_text("./a:b"),
+            Ok(FileSpec::Local(PathBuf::from("./a:b")))
+        );
+        assert!(FileSpec::from_text("a/b/c:/tmp").is_err());
+        assert!(FileSpec::from_text(":/tmp").is_err());
+        assert!(FileSpec::from_text("web:").is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fs;
 use std::io;
 use std::path::Path;
@@ -11,8 +12,20 @@ use k8s_openapi_ext as k8s;
 
 use k8s::metav1;
 
+pub use duration::Duration;
+pub use filespec::FileSpec;
 pub use kv::EnvFile;
 pub use kv::File;
 pub use kv::KeyValue;
+pub use port::PortMapping;
+pub use selector::FieldSelector;
+pub use selector::LabelSelector;
+pub use waitfor::Step;
+pub use waitfor::WaitFor;
 
+mod duration;
+mod filespec;
 mod kv;
+mod port;
+mod selector;
+mod waitfor;
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use k8s::intstr::IntOrString;
+
+use super::*;
+
+/// `[LOCAL:]REMOTE` port pair as accepted by `port-forward`.
+///
+/// `REMOTE` is either a port number or a port name, `LOCAL` defaults to the
+/// (resolved) remote port, and an empty or `0` `LOCAL` picks a random free port.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortMapping {
+    local: Option<u16>,
+    remote: IntOrString,
+}
+
+impl PortMapping {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    /// Local port to listen on, if given; `0` stands for a random port
+    pub fn local(&self) -> Option<u16> {
+        self.local
+    }
+
+    pub fn remote(&self) -> &IntOrString {
+        &self.remote
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        let (local, remote) = match text.split_once(':') {
+            Some(("", remote)) => (Some(0), remote),
+            Some((local, remote)) => {
+                let local = local
+                    .parse::<u16>()
+                    .map_err(|_| format!("invalid local port '{local}' in '{text}'"))?;
+                (Some(local), remote)
+            }
+            None => (None, text),
+        };
+        let remote = match remote.parse::<u16>() {
+            Ok(0) => return Err(format!("remote port cannot be 0 in '{text}'")),
+            Ok(port) => IntOrString::Int(i32::from(port)),
+            Err(_) if is_port_name(remote) => IntOrString::String(remote.to_string()),
+            Err(_) => return Err(format!("invalid remote port '{remote}' in '{text}'")),
+        };
+        Ok(Self { local, remote })
+    }
+}
+
+impl fmt::Display for PortMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remote = match &self.remote {
+            IntOrString::Int(port) => port.to_string(),
+            IntOrString::String(name) => name.clone(),
+        };
+        match self.local {
+            Some(local) => write!(f, "{local}:{remote}"),
+            None => remote.fmt(f),
+        }
+    }
+}
+
+// IANA service name: up to 15 lowercase alphanumerics or '-', with at least one letter
+fn is_port_name(text: &str) -> bool {
+    (1..=15).contains(&text.len())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && text.chars().any(|c| c.is_ascii_lowercase())
+        && !text.starts_with('-')
+        && !text.ends_with('-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn port_mappings() {
+        let mapping = PortMapping::from_text("8080:80").unwrap();
+        assert_eq!(mapping.local(), Some(8080));
+        assert_eq!(mapping.remote(), &IntOrString::Int(80));
+
+        let mapping = PortMapping::from_text("5432").unwrap();
+        assert_eq!(mapping.local(), None);
+        assert_eq!(mapping.remote(), &IntOrString::Int(5432));
+
+        let mapping = PortMapping::from_text(":http").unwrap();
+        assert_eq!(mapping.local(), Some(0));
+        assert_eq!(mapping.remote(), &IntOrString::String("http".to_string()));
+
+        assert!(PortMapping::from_text("8080:0").is_err());
+        assert!(PortMapping::from_text("x:80").is_err());
+        assert!(PortMapping::from_text("80:Bad_Name").is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+
+use kube::core::Expression;
+use kube::core::Selector;
+
+use super::*;
+
+/// Label query as accepted by `-l/--selector`.
+///
+/// Supports equality-based (`=`, `==`, `!=`) and set-based (`in`, `notin`, `key`, `!key`)
+/// requirements, e.g. `app=web,tier in (frontend,backend),!canary`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelSelector(Selector);
+
+impl LabelSelector {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    pub fn selector(&self) -> &Selector {
+        &self.0
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        split_requirements(text)?
+            .into_iter()
+            .map(expression)
+            .collect::<Result<Selector, _>>()
+            .map(Self)
+    }
+}
+
+impl fmt::Display for LabelSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Field query as accepted by `--field-selector`.
+///
+/// Only `=`, `==` and `!=` are supported by the API server, e.g. `status.phase=Running`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldSelector(Vec<FieldRequirement>);
+
+#[derive(Clone, Debug, PartialEq)]
+enum FieldRequirement {
+    Equal(String, String),
+    NotEqual(String, String),
+}
+
+impl FieldSelector {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        text.split(',')
+            .map(str::trim)
+            .filter(|requirement| !requirement.is_empty())
+            .map(FieldRequirement::from_text)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl FieldRequirement {
+    fn from_text(text: &str) -> Result<Self, String> {
+        let requirement = if let Some((key, value)) = text.split_once("!=") {
+            Self::NotEqual(key.trim().to_string(), value.trim().to_string())
+        } else if let Some((key, value)) = text.split_once("==") {
+            Self::Equal(key.trim().to_string(), value.trim().to_string())
+        } else if let Some((key, value)) = text.split_once('=') {
+            Self::Equal(key.trim().to_string(), value.trim().to_string())
+        } else {
+            return Err(format!(
+                "invalid field selector '{text}': expecting '=', '==' or '!='"
+            ));
+        };
+
+        match &requirement {
+            Self::Equal(key, _) | Self::NotEqual(key, _) if key.is_empty() => {
+                Err(format!("invalid field selector '{text}': empty field name"))
+            }
+            _ => Ok(requirement),
+        }
+    }
+}
+
+impl fmt::Display for FieldSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let requirements = self
+            .0
+            .iter()
+            .map(|requirement| match requirement {
+                FieldRequirement::Equal(key, value) => format!("{key}={value}"),
+                FieldRequirement::NotEqual(key, value) => format!("{key}!={value}"),
+            })
+            .collect::<Vec<_>>();
+        requirements.join(",").fmt(f)
+    }
+}
+
+// Split on commas that are not inside a `(...)` set
+fn split_requirements(text: &str) -> Result<Vec<&str>, String> {
+    let mut requirements = vec![];
+    let mut depth = 0_u32;
+    let mut start = 0;
+    for (idx, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("unbalanced ')' in label selector '{text}'"))?
+            }
+            ',' if depth == 0 => {
+                requirements.push(text[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(format!("unbalanced '(' in label selector '{text}'"));
+    }
+    requirements.push(text[start..].trim());
+    Ok(requirements
+        .into_iter()
+        .filter(|requirement| !requirement.is_empty())
+        .collect())
+}
+
+fn expression(text: &str) -> Result<Expression, String> {
+    let expression = if let Some(key) = text.strip_prefix('!') {
+        Expression::DoesNotExist(label_key(key)?)
+    } else if let Some((key, value)) = text.split_once("!=") {
+        Expression::NotEqual(label_key(key)?, label_value(value)?)
+    } else if let Some((key, value)) = text.split_once("==") {
+        Expression::Equal(label_key(key)?, label_value(value)?)
+    } else if let Some((key, value)) = text.split_once('=') {
+        Expression::Equal(label_key(key)?, label_value(value)?)
+    } else if let Some((key, values)) = set_operation(text, "notin") {
+        Expression::NotIn(label_key(key)?, label_values(values)?)
+    } else if let Some((key, values)) = set_operation(text, "in") {
+        Expression::In(label_key(key)?, label_values(values)?)
+    } else {
+        Expression::Exists(label_key(text)?)
+    };
+    Ok(expression)
+}
+
+// Matches `key <operator> (values)` returning key and values, the key ending at the first
+// whitespace or `(`, and the operator being followed by either, e.g. `tier in(web)`
+fn set_operation<'a>(text: &'a str, operator: &str) -> Option<(&'a str, &'a str)> {
+    let end = text.find(|c: char| c.is_whitespace() || c == '(')?;
+    let (key, rest) = text.split_at(end);
+    let values = rest.trim_start().strip_prefix(operator)?.trim_start();
+    let values = values.strip_prefix('(')?.strip_suffix(')')?;
+    Some((key, values))
+}
+
+fn label_values(text: &str) -> Result<BTreeSet<String>, String> {
+    if text.trim().is_empty() {
+        return Err("for 'in', 'notin' operators, values set can't be empty".to_string());
+    }
+    text.split(',').map(label_value).collect()
+}
+
+// [prefix/]name where prefix is a DNS subdomain and name is a qualified name
+fn label_key(text: &str) -> Result<String, String> {
+    let key = text.trim();
+    let (prefix, name) = key.rsplit_once('/').unwrap_or(("", key));
+    let valid_prefix = key.len() == name.len()
+        || (!prefix.is_empty()
+            && prefix.len() <= 253
+            && prefix.split('.').all(|label| {
+                is_qualified(label, |c| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'
+                })
+            }));
+    let valid_name = !name.is_empty()
+        && name.len() <= 63
+        && is_qualified(name, |c| {
+            c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+        });
+    if valid_prefix && valid_name {
+        Ok(key.to_string())
+    } else {
+        Err(format!("invalid label key '{key}'"))
+    }
+}
+
+fn label_value(text: &str) -> Result<String, String> {
+    let value = text.trim();
+    let valid = value.is_empty()
+        || (value.len() <= 63
+            && is_qualified(value, |c| {
+                c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+            }));
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!("invalid label value '{value}'"))
+    }
+}
+
+// Alphanumeric at both ends, `allowed` characters in between
+fn is_qualified(text: &str, allowed: impl Fn(char) -> bool) -> bool {
+    let starts_and_ends_alnum = text
+        .chars()
+        .next()
+        .zip(text.chars().next_back())
+        .is_some_and(|(first, last)| first.is_ascii_alphanumeric() && last.is_ascii_alphanumeric());
+    starts_and_ends_alnum && text.chars().all(allowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(text: &str) -> Result<String, String> {
+        LabelSelector::from_text(text).map(|selector| selector.to_string())
+    }
+
+    fn fields(text: &str) -> Result<String, String> {
+        FieldSelector::from_text(text).map(|selector| selector.to_string())
+    }
+
+    #[test]
+    fn equality_based() {
+        assert_eq!(labels("app=web").unwrap(), "app=web");
+        assert_eq!(labels("app==web").unwrap(), "app=web");
+        assert_eq!(labels("app!=web").unwrap(), "app!=web");
+        assert_eq!(
+            labels("app.kubernetes.io/name=web, tier=").unwrap(),
+            "app.kubernetes.io/name=web,tier="
+        );
+    }
+
+    #[test]
+    fn set_based() {
+        assert_eq!(
+            labels("tier in (frontend, backend),env notin (dev)").unwrap(),
+            "tier in (backend,frontend),env notin (dev)"
+        );
+        assert_eq!(labels("canary,!legacy").unwrap(), "canary,!legacy");
+        assert_eq!(
+            labels("tier in(frontend),env notin(dev)").unwrap(),
+            "tier in (frontend),env notin (dev)"
+        );
+        assert_eq!(labels("tier  in  ( web )").unwrap(), "tier in (web)");
+    }
+
+    #[test]
+    fn invalid_labels() {
+        assert!(labels("app=web=1").is_err());
+        assert!(labels("tier in (frontend").is_err());
+        assert!(labels("-app=web").is_err());
+        assert!(labels("Example.COM/app=web").is_err());
+        assert!(labels("tier in frontend").is_err());
+        assert!(labels("tier in ()").is_err());
+        assert!(labels("tier notin ( )").is_err());
+        assert!(labels("tier inside (web)").is_err());
+        assert!(labels("tierin(web)").is_err());
+    }
+
+    #[test]
+    fn field_selectors() {
+        assert_eq!(
+            fields("status.phase=Running,spec.nodeName!=node-1").unwrap(),
+            "status.phase=Running,spec.nodeName!=node-1"
+        );
+        assert_eq!(
+            fields("metadata.name==nginx").unwrap(),
+            "metadata.name=nginx"
+        );
+        assert!(fields("status.phase").is_err());
+        assert!(fields("=Running").is_err());
+    }
+}
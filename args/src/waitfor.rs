@@ -0,0 +1,250 @@
+use std::fmt;
+
+use serde_json as json;
+
+use super::*;
+
+/// What `wait --for` waits for: `delete`, `create`, `condition=NAME[=VALUE]`
+/// or `jsonpath=JSONPATH[=VALUE]`.
+///
+/// JSONPath expressions are limited to field names and array indices,
+/// e.g. `{.status.phase}` or `{.status.containerStatuses[0].ready}`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WaitFor {
+    Delete,
+    Create,
+    Condition {
+        name: String,
+        value: String,
+    },
+    JsonPath {
+        path: Vec<Step>,
+        value: Option<String>,
+    },
+}
+
+/// One step down a JSONPath expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+    Field(String),
+    Index(usize),
+}
+
+impl WaitFor {
+    pub fn value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(|text| Self::from_text(&text))
+    }
+
+    /// Whether `object`, `None` when it does not exist, is what is waited for
+    pub fn is_met(&self, object: Option<&json::Value>) -> bool {
+        match (self, object) {
+            (Self::Delete, object) => object.is_none(),
+            (_, None) => false,
+            (Self::Create, Some(_)) => true,
+            (Self::Condition { name, value }, Some(object)) => has_condition(object, name, value),
+            (Self::JsonPath { path, value }, Some(object)) => match (lookup(object, path), value) {
+                (None | Some(json::Value::Null), _) => false,
+                (Some(_), None) => true,
+                (Some(found), Some(value)) => scalar(found).is_some_and(|found| found == *value),
+            },
+        }
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "unrecognized condition '{text}', use delete, create, condition=NAME[=VALUE] or jsonpath=JSONPATH[=VALUE]"
+            )
+        };
+        match text.split_once('=') {
+            None if text.eq_ignore_ascii_case("delete") => Ok(Self::Delete),
+            None if text.eq_ignore_ascii_case("create") => Ok(Self::Create),
+            Some(("condition", condition)) => {
+                let (name, value) = condition.split_once('=').unwrap_or((condition, "True"));
+                if name.is_empty() {
+                    return Err(invalid());
+                }
+                Ok(Self::Condition {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                })
+            }
+            Some(("jsonpath", expression)) => {
+                let (path, value) = split_jsonpath(expression).ok_or_else(invalid)?;
+                let path = parse_jsonpath(path).ok_or_else(invalid)?;
+                Ok(Self::JsonPath { path, value })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for WaitFor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delete => f.write_str("delete"),
+            Self::Create => f.write_str("create"),
+            Self::Condition { name, value } => write!(f, "condition={name}={value}"),
+            Self::JsonPath { path, value } => {
+                f.write_str("jsonpath={")?;
+                for step in path {
+                    match step {
+                        Step::Field(field) => write!(f, ".{field}")?,
+                        Step::Index(index) => write!(f, "[{index}]")?,
+                    }
+                }
+                f.write_str("}")?;
+                value.iter().try_for_each(|value| write!(f, "={value}"))
+            }
+        }
+    }
+}
+
+/// Same as kubectl, condition types and statuses are compared ignoring case,
+/// and conditions observed for an older generation do not count
+fn has_condition(object: &json::Value, name: &str, value: &str) -> bool {
+    let generation = object["metadata"]["generation"].as_i64();
+    object["status"]["conditions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|condition| {
+            condition["type"]
+                .as_str()
+                .is_some_and(|type_| type_.eq_ignore_ascii_case(name))
+        })
+        .any(|condition| {
+            let observed = condition["observedGeneration"].as_i64();
+            let current = match (observed, generation) {
+                (Some(observed), Some(generation)) => observed >= generation,
+                _ => true,
+            };
+            current
+                && condition["status"]
+                    .as_str()
+                    .is_some_and(|status| status.eq_ignore_ascii_case(value))
+        })
+}
+
+/// `'{.path}'=value` into the path and the value, with optional quotes and braces
+fn split_jsonpath(expression: &str) -> Option<(&str, Option<String>)> {
+    let expression = expression.trim();
+    let (path, value) = match expression.find('}') {
+        Some(end) => {
+            let (path, rest) = expression.split_at(end + 1);
+            let rest = rest.trim_start_matches(['\'', '"']);
+            match rest.strip_prefix('=') {
+                Some(value) => (path, Some(value)),
+                None if rest.is_empty() => (path, None),
+                None => return None,
+            }
+        }
+        None => match expression.split_once('=') {
+            Some((path, value)) => (path, Some(value)),
+            None => (expression, None),
+        },
+    };
+    let path = path.trim_matches(['\'', '"']);
+    let path = path
+        .strip_prefix('{')
+        .and_then(|path| path.strip_suffix('}'))
+        .unwrap_or(path);
+    let value = value.map(|value| value.trim_matches(['\'', '"']).to_string());
+    Some((path, value))
+}
+
+fn parse_jsonpath(path: &str) -> Option<Vec<Step>> {
+    let path = path.strip_prefix('.')?;
+    let mut steps = vec![];
+    for segment in path.split('.') {
+        let (field, mut indices) = match segment.find('[') {
+            Some(start) => segment.split_at(start),
+            None => (segment, ""),
+        };
+        if field.is_empty() {
+            return None;
+        }
+        steps.push(Step::Field(field.to_string()));
+        while let Some(rest) = indices.strip_prefix('[') {
+            let (index, rest) = rest.split_once(']')?;
+            steps.push(Step::Index(index.parse().ok()?));
+            indices = rest;
+        }
+        if !indices.is_empty() {
+            return None;
+        }
+    }
+    Some(steps)
+}
+
+fn lookup<'a>(object: &'a json::Value, path: &[Step]) -> Option<&'a json::Value> {
+    path.iter().try_fold(object, |value, step| match step {
+        Step::Field(field) => value.get(field),
+        Step::Index(index) => value.get(index),
+    })
+}
+
+fn scalar(value: &json::Value) -> Option<String> {
+    match value {
+        json::Value::String(text) => Some(text.clone()),
+        json::Value::Bool(_) | json::Value::Number(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod() -> json::Value {
+        json::json!({
+            "metadata": {"name": "web", "generation": 2},
+            "status": {
+                "phase": "Running",
+                "conditions": [
+                    {"type": "Ready", "status": "True"},
+                    {"type": "Progressing", "status": "True", "observedGeneration": 1},
+                ],
+                "containerStatuses": [{"ready": true}],
+            },
+        })
+    }
+
+    #[test]
+    fn conditions() {
+        let ready = WaitFor::from_text("condition=ready").unwrap();
+        assert!(ready.is_met(Some(&pod())));
+        assert!(!ready.is_met(None));
+
+        let not_ready = WaitFor::from_text("condition=Ready=false").unwrap();
+        assert!(!not_ready.is_met(Some(&pod())));
+
+        // Observed for an older generation
+        let progressing = WaitFor::from_text("condition=Progressing").unwrap();
+        assert!(!progressing.is_met(Some(&pod())));
+
+        let delete = WaitFor::from_text("delete").unwrap();
+        assert!(delete.is_met(None));
+        assert!(!delete.is_met(Some(&pod())));
+    }
+
+    #[test]
+    fn jsonpath() {
+        let running = WaitFor::from_text("jsonpath='{.status.phase}'=Running").unwrap();
+        assert_eq!(running.to_string(), "jsonpath={.status.phase}=Running");
+        assert!(running.is_met(Some(&pod())));
+
+        let ready =
+            WaitFor::from_text("jsonpath={.status.containerStatuses[0].ready}=true").unwrap();
+        assert!(ready.is_met(Some(&pod())));
+
+        let exists = WaitFor::from_text("jsonpath={.status.phase}").unwrap();
+        assert!(exists.is_met(Some(&pod())));
+
+        let missing = WaitFor::from_text("jsonpath=.status.podIP").unwrap();
+        assert!(!missing.is_met(Some(&pod())));
+
+        assert!(WaitFor::from_text("jsonpath={status}").is_err());
+        assert!(WaitFor::from_text("ready").is_err());
+    }
+}
@@ -3,11 +3,25 @@ use super::*;
 pub trait APIResourceListExt: Sized {
     fn group_version(&self) -> Result<gvk::GroupVersion, gvk::ParseGroupVersionError>;
     fn find(self, name: &str) -> Option<metav1::APIResource>;
+    fn find_kind(self, kind: &str) -> Option<metav1::APIResource>;
     fn kube_api_resource(self, name: &str) -> Option<(discovery::Scope, api::ApiResource)> {
         let gv = self.group_version().ok()?;
         self.find(name)
             .map(|ar| (ar.scope(), ar.kube_api_resource(gv)))
     }
+    /// Same as `kube_api_resource()`, looking the resource up by `apiVersion` and `kind`
+    fn kube_api_resource_for(
+        self,
+        api_version: &str,
+        kind: &str,
+    ) -> Option<(discovery::Scope, api::ApiResource)> {
+        let gv = self.group_version().ok()?;
+        if gv.api_version() != api_version {
+            return None;
+        }
+        self.find_kind(kind)
+            .map(|ar| (ar.scope(), ar.kube_api_resource(gv)))
+    }
 }
 
 impl APIResourceListExt for metav1::APIResourceList {
@@ -18,6 +32,13 @@ impl APIResourceListExt for metav1::APIResourceList {
     fn find(self, name: &str) -> Option<metav1::APIResource> {
         self.resources.into_iter().find(|ar| ar.matches_name(name))
     }
+
+    fn find_kind(self, kind: &str) -> Option<metav1::APIResource> {
+        // Subresources (e.g. deployments/scale) carry a kind of their own, skip them
+        self.resources
+            .into_iter()
+            .find(|ar| ar.kind == kind && !ar.name.contains('/'))
+    }
 }
 
 pub trait APIResourceExt {
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Timing of `events.k8s.io/v1` events, taking into account both the series
+/// and the deprecated fields carried over from core/v1 events
+pub trait EventExt {
+    /// When the event was last observed
+    fn last_seen(&self) -> Option<jiff::Timestamp>;
+    /// When the event was first observed
+    fn first_seen(&self) -> Option<jiff::Timestamp>;
+    /// How many times the event was observed
+    fn count(&self) -> i32;
+}
+
+impl EventExt for eventsv1::Event {
+    fn last_seen(&self) -> Option<jiff::Timestamp> {
+        self.series
+            .as_ref()
+            .map(|series| series.last_observed_time.0)
+            .or_else(|| self.deprecated_last_timestamp.as_ref().map(|time| time.0))
+            .or_else(|| self.first_seen())
+    }
+
+    fn first_seen(&self) -> Option<jiff::Timestamp> {
+        self.event_time
+            .as_ref()
+            .map(|time| time.0)
+            .or_else(|| self.deprecated_first_timestamp.as_ref().map(|time| time.0))
+            .or_else(|| self.metadata.creation_timestamp.as_ref().map(|time| time.0))
+    }
+
+    fn count(&self) -> i32 {
+        self.series
+            .as_ref()
+            .map(|series| series.count)
+            .or(self.deprecated_count)
+            .unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json as json;
+
+    use super::*;
+
+    fn event(value: json::Value) -> eventsv1::Event {
+        json::from_value(value).unwrap()
+    }
+
+    fn time(time: &str) -> Option<jiff::Timestamp> {
+        Some(time.parse().unwrap())
+    }
+
+    #[test]
+    fn series() {
+        let event = event(json::json!({
+            "metadata": {"name": "web.1", "creationTimestamp": "2024-01-01T00:00:00Z"},
+            "eventTime": "2024-01-01T00:01:00.000000Z",
+            "deprecatedLastTimestamp": "2024-01-01T00:05:00Z",
+            "deprecatedCount": 2,
+            "series": {"count": 3, "lastObservedTime": "2024-01-01T00:10:00.000000Z"},
+        }));
+        assert_eq!(event.last_seen(), time("2024-01-01T00:10:00Z"));
+        assert_eq!(event.first_seen(), time("2024-01-01T00:01:00Z"));
+        assert_eq!(event.count(), 3);
+    }
+
+    #[test]
+    fn deprecated_fields() {
+        let event = event(json::json!({
+            "metadata": {"name": "web.1", "creationTimestamp": "2024-01-01T00:00:00Z"},
+            "deprecatedFirstTimestamp": "2024-01-01T00:02:00Z",
+            "deprecatedLastTimestamp": "2024-01-01T00:05:00Z",
+            "deprecatedCount": 2,
+        }));
+        assert_eq!(event.last_seen(), time("2024-01-01T00:05:00Z"));
+        assert_eq!(event.first_seen(), time("2024-01-01T00:02:00Z"));
+        assert_eq!(event.count(), 2);
+    }
+
+    #[test]
+    fn creation_timestamp() {
+        let event = event(json::json!({
+            "metadata": {"name": "web.1", "creationTimestamp": "2024-01-01T00:00:00Z"},
+        }));
+        assert_eq!(event.last_seen(), time("2024-01-01T00:00:00Z"));
+        assert_eq!(event.first_seen(), time("2024-01-01T00:00:00Z"));
+        assert_eq!(event.count(), 1);
+    }
+}
@@ -9,18 +9,26 @@ use k8s::NamespaceGetExt;
 use k8s::PodGetExt;
 
 use k8s::corev1;
+use k8s::eventsv1;
 use k8s::metav1;
+use k8s::openapi::jiff;
 
 pub use apiresource::APIResourceExt;
 pub use apiresource::APIResourceListExt;
 pub use b64::Base64Decode;
 pub use b64::Base64Encode;
+pub use event::EventExt;
 pub use namespace::NamespaceGetExt2;
 pub use pod::PodGetExt2;
 pub use service::ServiceGetExt2;
+pub use table::Table;
+pub use table::TableColumnDefinition;
+pub use table::TableRow;
 
 mod apiresource;
 mod b64;
+mod event;
 mod namespace;
 mod pod;
 mod service;
+mod table;
@@ -0,0 +1,50 @@
+use super::*;
+
+/// Server-side `meta.k8s.io/v1` Table, returned when requesting `application/json;as=Table`
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Table {
+    #[serde(flatten, default)]
+    pub types: Option<api::TypeMeta>,
+    #[serde(default)]
+    pub metadata: metav1::ListMeta,
+    #[serde(default)]
+    pub column_definitions: Vec<TableColumnDefinition>,
+    #[serde(default)]
+    pub rows: Vec<TableRow>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableColumnDefinition {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub format: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRow {
+    #[serde(default)]
+    pub cells: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object: Option<api::DynamicObject>,
+}
+
+impl Table {
+    pub const ACCEPT: &str = "application/json;as=Table;v=v1;g=meta.k8s.io";
+}
+
+impl TableColumnDefinition {
+    pub fn is_date(&self) -> bool {
+        self.type_ == "date"
+    }
+}
@@ -12,6 +12,11 @@ impl Kubeapi {
         self.namespaced_api()
     }
 
+    /// `corev1::Pod` in a given `namespace`, regardless of the current one
+    pub fn namespaced_pods(&self, namespace: &str) -> kube::Result<api::Api<corev1::Pod>> {
+        self.client().map(|client| client.namespaced_api(namespace))
+    }
+
     /// `corev1::ConfigMap`
     pub fn configmaps(&self) -> kube::Result<api::Api<corev1::ConfigMap>> {
         self.namespaced_api()
@@ -27,6 +32,16 @@ impl Kubeapi {
         self.namespaced_api()
     }
 
+    /// `corev1::Event`
+    pub fn core_events(&self) -> kube::Result<api::Api<corev1::Event>> {
+        self.namespaced_api()
+    }
+
+    /// `eventsv1::Event`
+    pub fn events(&self) -> kube::Result<api::Api<eventsv1::Event>> {
+        self.namespaced_api()
+    }
+
     /// `corev1::ComponentStatus`
     pub fn componentstatuses(&self) -> kube::Result<api::Api<corev1::ComponentStatus>> {
         self.cluster_api()
@@ -37,6 +52,38 @@ impl Kubeapi {
         self.cluster_api()
     }
 
+    /// `appsv1::Deployment`
+    pub fn deployments(&self) -> kube::Result<api::Api<appsv1::Deployment>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::StatefulSet`
+    pub fn statefulsets(&self) -> kube::Result<api::Api<appsv1::StatefulSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::DaemonSet`
+    pub fn daemonsets(&self) -> kube::Result<api::Api<appsv1::DaemonSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::ReplicaSet`
+    pub fn replicasets(&self) -> kube::Result<api::Api<appsv1::ReplicaSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::ControllerRevision`
+    pub fn controllerrevisions(&self) -> kube::Result<api::Api<appsv1::ControllerRevision>> {
+        self.namespaced_api()
+    }
+
+    /// `autoscalingv2::HorizontalPodAutoscaler`
+    pub fn horizontalpodautoscalers(
+        &self,
+    ) -> kube::Result<api::Api<autoscalingv2::HorizontalPodAutoscaler>> {
+        self.namespaced_api()
+    }
+
     /// `rbacv1::ClusterRole`
     pub fn clusterroles(&self) -> kube::Result<api::Api<rbacv1::ClusterRole>> {
         self.cluster_api()
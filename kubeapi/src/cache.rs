@@ -11,6 +11,9 @@ pub struct Cache {
 }
 
 impl Cache {
+    /// Same as kubectl, cached discovery data is trusted for six hours
+    pub(super) const TTL: time::Duration = time::Duration::from_secs(6 * 60 * 60);
+
     pub(super) fn try_load(self, path: impl AsRef<Path>) -> Self {
         let start = time::Instant::now();
         let cached_resources = CachedResources::new(path);
@@ -10,3 +10,14 @@ pub enum DryRun {
     /// If client strategy, only print the object that would be sent, without sending it.
     Client,
 }
+
+impl DryRun {
+    /// Same as kubectl, appended to the report of each object handled
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Server => " (server dry run)",
+            Self::Client => " (dry run)",
+        }
+    }
+}
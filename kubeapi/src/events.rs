@@ -0,0 +1,230 @@
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use futures_util::stream::BoxStream;
+
+use super::*;
+
+/// Where events are read from, `events.k8s.io/v1` unless the server does not serve it.
+/// Either way they are handed out as `eventsv1::Event`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventsApi {
+    EventsV1,
+    CoreV1,
+}
+
+impl EventsApi {
+    /// Field selector for the events regarding the `kind` object named `name`
+    pub fn regarding_field_selector(self, kind: &str, name: &str) -> String {
+        let field = match self {
+            Self::EventsV1 => "regarding",
+            Self::CoreV1 => "involvedObject",
+        };
+        format!("{field}.kind={kind},{field}.name={name}")
+    }
+}
+
+/// Events related to a particular object
+impl Kubeapi {
+    const NOT_FOUND: u16 = 404;
+
+    /// Events whose `involvedObject.uid` is `uid`, looked up in `namespace`,
+    /// or across all the namespaces for cluster scoped objects
+    pub async fn object_events(
+        &self,
+        namespace: Option<&str>,
+        uid: &str,
+    ) -> kube::Result<Vec<corev1::Event>> {
+        let client = self.client()?;
+        let api = match namespace {
+            Some(namespace) => client.namespaced_api::<corev1::Event>(namespace),
+            None => client.api::<corev1::Event>(),
+        };
+        let lp = api::ListParams::default().fields(&format!("involvedObject.uid={uid}"));
+        let events = api.list(&lp).await?;
+        Ok(events.items)
+    }
+
+    /// Events in the current namespace, narrowed down by the selectors and by `regarding` (kind, name).
+    /// Falls back to core/v1 events when `events.k8s.io/v1` is not available.
+    pub async fn list_events(
+        &self,
+        regarding: Option<(&str, &str)>,
+    ) -> kube::Result<(EventsApi, api::ObjectList<eventsv1::Event>)> {
+        let lp = self.events_list_params(EventsApi::EventsV1, regarding);
+        match self.events()?.list(&lp).await {
+            Ok(list) => Ok((EventsApi::EventsV1, list)),
+            Err(kube::Error::Api(status)) if status.code == Self::NOT_FOUND => {
+                debug!("events.k8s.io/v1 is not available, using core/v1 events");
+                let lp = self.events_list_params(EventsApi::CoreV1, regarding);
+                let list = self.core_events()?.list(&lp).await?;
+                let list = api::ObjectList {
+                    types: list.types,
+                    metadata: list.metadata,
+                    items: list.items.into_iter().map(from_core).collect(),
+                };
+                Ok((EventsApi::CoreV1, list))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Watch for events from `version` on, same scope as `list_events()`
+    pub async fn watch_events(
+        &self,
+        events: EventsApi,
+        regarding: Option<(&str, &str)>,
+        version: &str,
+    ) -> kube::Result<BoxStream<'static, kube::Result<api::WatchEvent<eventsv1::Event>>>> {
+        let mut wp = self.watch_params();
+        if let Some(fields) = self.events_field_selector(events, regarding) {
+            wp = wp.fields(&fields);
+        }
+        let stream = match events {
+            EventsApi::EventsV1 => self.events()?.watch(&wp, version).await?.boxed(),
+            EventsApi::CoreV1 => self
+                .core_events()?
+                .watch(&wp, version)
+                .await?
+                .map_ok(|event| match event {
+                    api::WatchEvent::Added(event) => api::WatchEvent::Added(from_core(event)),
+                    api::WatchEvent::Modified(event) => api::WatchEvent::Modified(from_core(event)),
+                    api::WatchEvent::Deleted(event) => api::WatchEvent::Deleted(from_core(event)),
+                    api::WatchEvent::Bookmark(bookmark) => api::WatchEvent::Bookmark(bookmark),
+                    api::WatchEvent::Error(status) => api::WatchEvent::Error(status),
+                })
+                .boxed(),
+        };
+        Ok(stream)
+    }
+
+    fn events_list_params(
+        &self,
+        events: EventsApi,
+        regarding: Option<(&str, &str)>,
+    ) -> api::ListParams {
+        let lp = self.list_params();
+        match self.events_field_selector(events, regarding) {
+            Some(fields) => lp.fields(&fields),
+            None => lp,
+        }
+    }
+
+    fn events_field_selector(
+        &self,
+        events: EventsApi,
+        regarding: Option<(&str, &str)>,
+    ) -> Option<String> {
+        let (kind, name) = regarding?;
+        let fields = self
+            .field_selector
+            .iter()
+            .cloned()
+            .chain(Some(events.regarding_field_selector(kind, name)))
+            .collect::<Vec<_>>()
+            .join(",");
+        Some(fields)
+    }
+}
+
+fn from_core(event: corev1::Event) -> eventsv1::Event {
+    let series = event.series.and_then(|series| {
+        Some(eventsv1::EventSeries {
+            count: series.count?,
+            last_observed_time: series.last_observed_time?,
+        })
+    });
+    eventsv1::Event {
+        action: event.action,
+        deprecated_count: event.count,
+        deprecated_first_timestamp: event.first_timestamp,
+        deprecated_last_timestamp: event.last_timestamp,
+        deprecated_source: event.source,
+        event_time: event.event_time,
+        metadata: event.metadata,
+        note: event.message,
+        reason: event.reason,
+        regarding: Some(event.involved_object),
+        related: event.related,
+        reporting_controller: event.reporting_component,
+        reporting_instance: event.reporting_instance,
+        series,
+        type_: event.type_,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_event() {
+        let event = json::from_value::<corev1::Event>(json::json!({
+            "metadata": {"name": "web.1", "namespace": "default"},
+            "involvedObject": {"kind": "Pod", "name": "web", "fieldPath": "spec.containers{web}"},
+            "type": "Warning",
+            "reason": "BackOff",
+            "message": "Back-off restarting failed container",
+            "count": 4,
+            "firstTimestamp": "2024-01-01T00:00:00Z",
+            "lastTimestamp": "2024-01-01T00:10:00Z",
+            "source": {"component": "kubelet", "host": "node-1"},
+            "reportingComponent": "kubelet",
+        }))
+        .unwrap();
+
+        let event = from_core(event);
+        assert_eq!(event.metadata.name.as_deref(), Some("web.1"));
+        let regarding = event.regarding.unwrap();
+        assert_eq!(regarding.kind.as_deref(), Some("Pod"));
+        assert_eq!(regarding.name.as_deref(), Some("web"));
+        assert_eq!(
+            regarding.field_path.as_deref(),
+            Some("spec.containers{web}")
+        );
+        assert_eq!(event.type_.as_deref(), Some("Warning"));
+        assert_eq!(event.reason.as_deref(), Some("BackOff"));
+        assert_eq!(
+            event.note.as_deref(),
+            Some("Back-off restarting failed container")
+        );
+        assert_eq!(event.deprecated_count, Some(4));
+        let last = event.deprecated_last_timestamp.unwrap();
+        assert_eq!(last.0, "2024-01-01T00:10:00Z".parse().unwrap());
+        let first = event.deprecated_first_timestamp.unwrap();
+        assert_eq!(first.0, "2024-01-01T00:00:00Z".parse().unwrap());
+        let source = event.deprecated_source.unwrap();
+        assert_eq!(source.component.as_deref(), Some("kubelet"));
+        assert_eq!(source.host.as_deref(), Some("node-1"));
+        assert_eq!(event.reporting_controller.as_deref(), Some("kubelet"));
+        assert!(event.series.is_none());
+    }
+
+    #[test]
+    fn core_event_series() {
+        let event = json::from_value::<corev1::Event>(json::json!({
+            "metadata": {"name": "web.1"},
+            "involvedObject": {"kind": "Pod", "name": "web"},
+            "series": {"count": 7, "lastObservedTime": "2024-01-01T00:10:00.000000Z"},
+        }))
+        .unwrap();
+
+        let series = from_core(event).series.unwrap();
+        assert_eq!(series.count, 7);
+        assert_eq!(
+            series.last_observed_time.0,
+            "2024-01-01T00:10:00Z".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn regarding_field_selector() {
+        assert_eq!(
+            EventsApi::EventsV1.regarding_field_selector("Pod", "web"),
+            "regarding.kind=Pod,regarding.name=web"
+        );
+        assert_eq!(
+            EventsApi::CoreV1.regarding_field_selector("Pod", "web"),
+            "involvedObject.kind=Pod,involvedObject.name=web"
+        );
+    }
+}
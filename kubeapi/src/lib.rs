@@ -21,34 +21,44 @@ use tracing::error;
 use tracing::info;
 use tracing::trace;
 
+use k8s::appsv1;
 use k8s::authenticationv1;
 use k8s::authorizationv1;
+use k8s::autoscalingv2;
 use k8s::corev1;
+use k8s::eventsv1;
 use k8s::metav1;
 use k8s::rbacv1;
 
+use rkubectl_ext::Table;
 use rkubectl_features::Feature;
 
 pub use cache::Cache;
 pub use cascade::Cascade;
 pub use dryrun::DryRun;
+pub use events::EventsApi;
 pub use namespace::Namespace;
 pub use options::KubeConfigOptions;
 pub use options::KubeapiOptions;
+pub use wait::watcher_error;
 
 mod apis;
 mod cache;
 mod cascade;
 mod dryrun;
+mod events;
 mod features;
 mod info;
 mod kubeconfig;
 mod namespace;
+mod openapi;
 mod options;
 mod params;
 mod raw;
 mod server;
+mod table;
 mod version;
+mod wait;
 
 /// Kubeapi is a higher-level Kubernetes API client that provides additional features
 /// such as caching, namespace management, and easier access to common Kubernetes resources.
@@ -58,6 +68,8 @@ pub struct Kubeapi {
     kubeconfig: kube::config::Kubeconfig,
     cache: Cache,
     namespace: Namespace,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
     debug: bool,
     options: KubeapiOptions,
 }
@@ -80,6 +92,8 @@ impl Kubeapi {
                 kubeconfig,
                 cache,
                 namespace,
+                label_selector: None,
+                field_selector: None,
                 debug,
                 options,
             })
@@ -127,6 +141,27 @@ impl Kubeapi {
         &self.namespace
     }
 
+    /// Set the label and field selectors applied to all `LIST` operations.
+    /// This method returns a new instance with the updated selectors.
+    pub fn with_selectors(
+        self,
+        label_selector: Option<impl ToString>,
+        field_selector: Option<impl ToString>,
+    ) -> Self {
+        let label_selector = label_selector.map(|selector| selector.to_string());
+        let field_selector = field_selector.map(|selector| selector.to_string());
+        Self {
+            label_selector,
+            field_selector,
+            ..self
+        }
+    }
+
+    /// Whether `LIST` operations are narrowed down by a label selector
+    pub fn has_label_selector(&self) -> bool {
+        self.label_selector.is_some()
+    }
+
     pub fn show_namespace(&self) -> bool {
         matches!(self.namespace, Namespace::All)
     }
@@ -188,6 +223,30 @@ impl Kubeapi {
         Ok(dynamic_api)
     }
 
+    /// Same as `dynamic_object_api()`, but for an object that may name its own `namespace`.
+    /// Namespaced objects without one go to the current namespace, or the default one with `--all-namespaces`.
+    pub fn object_api(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+        namespace: Option<&str>,
+    ) -> kube::Result<api::Api<api::DynamicObject>> {
+        let client = self.client()?;
+        let object_api = match (scope, namespace) {
+            (discovery::Scope::Cluster, _) => api::Api::all_with(client, dyntype),
+            (discovery::Scope::Namespaced, Some(ns)) => {
+                api::Api::namespaced_with(client, ns, dyntype)
+            }
+            (discovery::Scope::Namespaced, None) => match &self.namespace {
+                Namespace::Namespace(ns) => api::Api::namespaced_with(client, ns, dyntype),
+                Namespace::All | Namespace::Default => {
+                    api::Api::default_namespaced_with(client, dyntype)
+                }
+            },
+        };
+        Ok(object_api)
+    }
+
     pub fn inspect<K>(&self, k: &K)
     where
         K: serde::Serialize,
@@ -227,6 +286,8 @@ impl Kubeapi {
             kubeconfig: default(),
             cache: default(),
             namespace: default(),
+            label_selector: default(),
+            field_selector: default(),
             debug: default(),
             options: default(),
         }
@@ -0,0 +1,85 @@
+use super::*;
+
+/// OpenAPI v3 schemas, cached next to the discovery cache
+impl Kubeapi {
+    /// The OpenAPI v3 document for `group_version`, e.g. `v1` or `apps/v1`,
+    /// or `None` when the server does not publish one
+    pub async fn openapi_v3(&self, group_version: &str) -> kube::Result<Option<json::Value>> {
+        let index = self.openapi_v3_index().await?;
+        let path = if group_version.contains('/') {
+            format!("apis/{group_version}")
+        } else {
+            format!("api/{group_version}")
+        };
+        let Some(url) = index["paths"][&path]["serverRelativeURL"].as_str() else {
+            return Ok(None);
+        };
+
+        // Documents addressed by the hash of their content never go stale,
+        // those without one may change anytime and are not cached
+        let cached = url
+            .split_once("hash=")
+            .map(|(_, hash)| hash.split('&').next().unwrap_or(hash))
+            .and_then(|hash| self.openapi_v3_cache(&path, &format!("{hash}.json")));
+        if let Some(document) = cached.as_ref().and_then(|cached| read_cached(cached, None)) {
+            return Ok(Some(document));
+        }
+
+        let document = self.openapi_v3_get(url).await?;
+        if let Some(cached) = cached {
+            write_cached(&cached, &document);
+        }
+        Ok(Some(document))
+    }
+
+    /// The `/openapi/v3` index of the documents, cached as long as the discovery data
+    async fn openapi_v3_index(&self) -> kube::Result<json::Value> {
+        let cached = self.openapi_v3_cache("", "index.json");
+        if let Some(index) = cached
+            .as_ref()
+            .and_then(|cached| read_cached(cached, Some(Cache::TTL)))
+        {
+            return Ok(index);
+        }
+
+        let index = self.openapi_v3_get("/openapi/v3").await?;
+        if let Some(cached) = cached {
+            write_cached(&cached, &index);
+        }
+        Ok(index)
+    }
+
+    async fn openapi_v3_get(&self, url: &str) -> kube::Result<json::Value> {
+        let request = http::Request::get(url)
+            .body(vec![])
+            .map_err(kube::Error::HttpError)?;
+        self.client()?.request::<json::Value>(request).await
+    }
+
+    fn openapi_v3_cache(&self, path: &str, file: &str) -> Option<PathBuf> {
+        let cache = self.cache_path().ok()?;
+        Some(cache.join("openapi").join("v3").join(path).join(file))
+    }
+}
+
+/// The cached document at `path`, unless older than `ttl`
+fn read_cached(path: &Path, ttl: Option<time::Duration>) -> Option<json::Value> {
+    if let Some(ttl) = ttl {
+        let age = fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()?;
+        if age > ttl {
+            return None;
+        }
+    }
+    let text = fs::read_to_string(path).ok()?;
+    json::from_str(&text).ok()
+}
+
+fn write_cached(path: &Path, document: &json::Value) {
+    let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, document.to_string()));
+    if let Err(err) = saved {
+        debug!(%err, path = %path.display(), "Failed to cache the OpenAPI document");
+    }
+}
@@ -7,9 +7,47 @@ impl Kubeapi {
         api::GetParams::default()
     }
 
-    /// Default parameters for `LIST` operations
+    /// Default parameters for `LIST` operations, narrowed down by the label and field selectors
     pub fn list_params(&self) -> api::ListParams {
-        api::ListParams::default()
+        api::ListParams {
+            label_selector: self.label_selector.clone(),
+            field_selector: self.field_selector.clone(),
+            ..default()
+        }
+    }
+
+    /// Parameters for `LIST` operations with `selector` added to the label selector
+    pub fn list_params_with_selector(&self, selector: &kube::core::Selector) -> api::ListParams {
+        let label_selector = self
+            .label_selector
+            .iter()
+            .cloned()
+            .chain(Some(selector.to_string()))
+            .collect::<Vec<_>>()
+            .join(",");
+        api::ListParams {
+            label_selector: Some(label_selector),
+            ..self.list_params()
+        }
+    }
+
+    /// Default parameters for `WATCH` operations, narrowed down by the label and field selectors
+    pub fn watch_params(&self) -> api::WatchParams {
+        api::WatchParams {
+            label_selector: self.label_selector.clone(),
+            field_selector: self.field_selector.clone(),
+            ..default()
+        }
+    }
+
+    /// Field selector matching a single object by `name`, on top of the current field selector
+    pub fn name_field_selector(&self, name: &str) -> String {
+        self.field_selector
+            .iter()
+            .cloned()
+            .chain(Some(format!("metadata.name={name}")))
+            .collect::<Vec<_>>()
+            .join(",")
     }
 
     /// Default parameters for `POST` operations
@@ -31,6 +69,25 @@ impl Kubeapi {
         }
     }
 
+    /// Parameters for server-side apply `PATCH` operations
+    pub fn apply_params(&self, manager: &str, force: bool, dry_run: DryRun) -> api::PatchParams {
+        let pp = api::PatchParams::apply(manager);
+        let pp = if force { pp.force() } else { pp };
+        match dry_run {
+            DryRun::Server => pp.dry_run(),
+            DryRun::None | DryRun::Client => pp,
+        }
+    }
+
+    /// Parameters for `PATCH` operations with a specified field manager
+    pub fn patch_params(&self, manager: &str, dry_run: DryRun) -> api::PatchParams {
+        api::PatchParams {
+            field_manager: Some(manager.to_string()),
+            dry_run: dry_run == DryRun::Server,
+            ..default()
+        }
+    }
+
     /// Parameters for `POST` operations with a specified field manager
     pub fn post_params_with_manager(&self, manager: &str) -> api::PostParams {
         api::PostParams {
@@ -38,4 +95,12 @@ impl Kubeapi {
             ..default()
         }
     }
+
+    /// Parameters for `POST` operations with a specified field manager, possibly as a dry run
+    pub fn post_params_with_dry_run(&self, manager: &str, dry_run: DryRun) -> api::PostParams {
+        api::PostParams {
+            dry_run: dry_run == DryRun::Server,
+            ..self.post_params_with_manager(manager)
+        }
+    }
 }
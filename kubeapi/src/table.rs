@@ -0,0 +1,59 @@
+use http::header;
+
+use super::*;
+
+/// Server-side `meta.k8s.io/v1` Table rendering
+impl Kubeapi {
+    /// List objects as a server-side Table
+    pub async fn list_table(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+    ) -> kube::Result<Table> {
+        let lp = self.list_params();
+        let api = self.dynamic_object_api(scope, dyntype)?;
+        let request = api::Request::new(api.resource_url())
+            .list(&lp)
+            .map_err(kube::Error::BuildRequest)?;
+        self.table(request, dyntype).await
+    }
+
+    /// Get a single object as a server-side Table
+    pub async fn get_table(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+        name: &str,
+    ) -> kube::Result<Table> {
+        let gp = self.get_params();
+        let api = self.dynamic_object_api(scope, dyntype)?;
+        let request = api::Request::new(api.resource_url())
+            .get(name, &gp)
+            .map_err(kube::Error::BuildRequest)?;
+        self.table(request, dyntype).await
+    }
+
+    async fn table(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+        dyntype: &discovery::ApiResource,
+    ) -> kube::Result<Table> {
+        request.headers_mut().insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static(Table::ACCEPT),
+        );
+        let mut table = self.client()?.request::<Table>(request).await?;
+        // Row objects come as PartialObjectMetadata, make them look like the real thing
+        table
+            .rows
+            .iter_mut()
+            .filter_map(|row| row.object.as_mut())
+            .for_each(|object| {
+                object.types = Some(api::TypeMeta {
+                    api_version: dyntype.api_version.clone(),
+                    kind: dyntype.kind.clone(),
+                })
+            });
+        Ok(table)
+    }
+}
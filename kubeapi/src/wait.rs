@@ -0,0 +1,67 @@
+use super::*;
+
+use kube::runtime::wait;
+use kube::runtime::watcher;
+
+/// Waiting for objects to change
+impl Kubeapi {
+    /// Waits until `condition` holds for the `name` object, `None` meaning the object does not exist.
+    /// The object is checked at least once, and `false` is returned when `timeout` runs out first.
+    pub async fn wait_until<K>(
+        &self,
+        api: &api::Api<K>,
+        name: &str,
+        condition: impl Fn(Option<&K>) -> bool,
+        timeout: Option<time::Duration>,
+    ) -> kube::Result<bool>
+    where
+        K: Clone + fmt::Debug + Send + serde::de::DeserializeOwned + kube::Resource + 'static,
+    {
+        if timeout.is_some_and(|timeout| timeout.is_zero()) {
+            let object = api.get_opt(name).await?;
+            return Ok(condition(object.as_ref()));
+        }
+
+        let watch = wait::await_condition(api.clone(), name, condition);
+        let result = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, watch).await {
+                Ok(result) => result,
+                Err(_elapsed) => return Ok(false),
+            },
+            None => watch.await,
+        };
+        result
+            .map(|_| true)
+            .map_err(|wait::Error::ProbeFailed(err)| watcher_error(err))
+    }
+
+    /// Waits until the `name` object is gone, or has been replaced by an object other than `uid`.
+    /// Returns `false` when `timeout` runs out first.
+    pub async fn wait_deleted<K>(
+        &self,
+        api: &api::Api<K>,
+        name: &str,
+        uid: Option<&str>,
+        timeout: Option<time::Duration>,
+    ) -> kube::Result<bool>
+    where
+        K: Clone + fmt::Debug + Send + serde::de::DeserializeOwned + kube::Resource + 'static,
+    {
+        let deleted = |object: Option<&K>| match object {
+            None => true,
+            Some(object) => uid.is_some() && object.meta().uid.as_deref() != uid,
+        };
+        self.wait_until(api, name, deleted, timeout).await
+    }
+}
+
+/// The API error behind a failed watch
+pub fn watcher_error(err: watcher::Error) -> kube::Error {
+    match err {
+        watcher::Error::InitialListFailed(err)
+        | watcher::Error::WatchStartFailed(err)
+        | watcher::Error::WatchFailed(err) => err,
+        watcher::Error::WatchError(status) => kube::Error::Api(status),
+        err @ watcher::Error::NoResourceVersion => kube::Error::Service(err.into()),
+    }
+}
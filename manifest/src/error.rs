@@ -0,0 +1,54 @@
+use super::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ManifestError {
+    #[error("{origin}: {error}")]
+    Read { origin: String, error: io::Error },
+    #[error("{url}: {reason}")]
+    Fetch { url: String, reason: String },
+    #[error("{at}: {reason}")]
+    Parse { at: Source, reason: String },
+    #[error("{at}: {error}")]
+    Object { at: Source, error: Box<kube::Error> },
+    #[error("no objects passed in {0}")]
+    Empty(String),
+    #[error(
+        "{at}: the namespace from the provided object \"{object}\" does not match the namespace \"{namespace}\". You must pass '--namespace={object}' to perform this operation."
+    )]
+    NamespaceMismatch {
+        at: Source,
+        object: String,
+        namespace: String,
+    },
+}
+
+impl ManifestError {
+    pub fn read(origin: &str, error: io::Error) -> Self {
+        Self::Read {
+            origin: origin.to_string(),
+            error,
+        }
+    }
+
+    pub fn fetch(url: &str, reason: impl ToString) -> Self {
+        Self::Fetch {
+            url: url.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn parse(at: Source, reason: impl ToString) -> Self {
+        Self::Parse {
+            at,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// `error` while handling the object read at `at`
+    pub fn object(at: Source, error: kube::Error) -> Self {
+        Self::Object {
+            at,
+            error: Box::new(error),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::time;
+
+use http::header;
+use http_body_util::BodyExt;
+use http_body_util::Empty;
+use hyper::body::Bytes;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+
+use super::*;
+
+const MAX_REDIRECTS: usize = 10;
+const TIMEOUT: time::Duration = time::Duration::from_secs(30);
+
+pub(crate) fn is_url(filename: &str) -> bool {
+    filename.starts_with("http://") || filename.starts_with("https://")
+}
+
+/// Body of the document at `url`, following redirects, giving up after `TIMEOUT`
+pub(crate) async fn fetch(url: &str) -> Result<String, ManifestError> {
+    fetch_within(url, TIMEOUT).await
+}
+
+async fn fetch_within(url: &str, timeout: time::Duration) -> Result<String, ManifestError> {
+    tokio::time::timeout(timeout, get(url))
+        .await
+        .unwrap_or_else(|_elapsed| {
+            let reason = format!("timed out after {}s", timeout.as_secs_f64());
+            Err(ManifestError::fetch(url, reason))
+        })
+}
+
+async fn get(url: &str) -> Result<String, ManifestError> {
+    let failed = |reason: String| ManifestError::fetch(url, reason);
+    let https = hyper_rustls::HttpsConnectorBuilder::new()
+        .with_native_roots()
+        .map_err(|err| failed(err.to_string()))?
+        .https_or_http()
+        .enable_http1()
+        .build();
+    let client = Client::builder(TokioExecutor::new()).build::<_, Empty<Bytes>>(https);
+
+    let mut uri = url
+        .parse::<http::Uri>()
+        .map_err(|err| failed(err.to_string()))?;
+    for _ in 0..MAX_REDIRECTS {
+        let response = client
+            .get(uri.clone())
+            .await
+            .map_err(|err| failed(err.to_string()))?;
+        let status = response.status();
+        if status.is_redirection() {
+            let location = response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .ok_or_else(|| failed(format!("{status} without a location")))?;
+            uri = redirect(&uri, location).map_err(|err| failed(err.to_string()))?;
+            continue;
+        }
+        if !status.is_success() {
+            return Err(failed(status.to_string()));
+        }
+
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(|err| failed(err.to_string()))?
+            .to_bytes();
+        return String::from_utf8(body.to_vec()).map_err(|err| failed(err.to_string()));
+    }
+
+    Err(failed(format!("stopped after {MAX_REDIRECTS} redirects")))
+}
+
+/// `location` may be relative to the URI that redirected to it
+fn redirect(uri: &http::Uri, location: &str) -> Result<http::Uri, http::Error> {
+    let location = location.parse::<http::Uri>()?;
+    if location.scheme().is_some() {
+        return Ok(location);
+    }
+    let mut parts = http::uri::Parts::default();
+    parts.scheme = uri.scheme().cloned();
+    parts.authority = uri.authority().cloned();
+    parts.path_and_query = location.path_and_query().cloned();
+    Ok(http::Uri::from_parts(parts)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn times_out() {
+        // Connections are accepted by the OS, but no response ever comes
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/manifest.yaml", listener.local_addr().unwrap());
+        let timeout = time::Duration::from_millis(100);
+
+        let err = fetch_within(&url, timeout).await.unwrap_err();
+        assert!(
+            matches!(&err, ManifestError::Fetch { reason, .. } if reason.starts_with("timed out")),
+            "{err}"
+        );
+    }
+}
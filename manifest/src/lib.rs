@@ -0,0 +1,102 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use kube::api;
+use serde_json as json;
+
+pub use error::ManifestError;
+
+mod error;
+mod fetch;
+mod parse;
+
+/// Object read from a manifest, along with where it was read from
+#[derive(Clone, Debug)]
+pub struct Manifest {
+    pub object: api::DynamicObject,
+    pub source: Source,
+}
+
+/// The file, URL or stdin an object was read from, and the line its document starts on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Source {
+    pub origin: String,
+    pub line: usize,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.origin, self.line)
+    }
+}
+
+/// Objects from the `-f` arguments of commands taking manifests.
+///
+/// Each argument is a file, a directory (only walked into with `recursive`), an `http(s)://` URL
+/// or `-` for stdin. Every file may hold several YAML or JSON documents, and `List` objects are
+/// expanded into their items.
+pub async fn load(filenames: &[String], recursive: bool) -> Result<Vec<Manifest>, ManifestError> {
+    let mut manifests = vec![];
+    for filename in filenames {
+        if filename == "-" {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| ManifestError::read("<stdin>", err))?;
+            manifests.extend(parse::parse(&text, "<stdin>")?);
+        } else if fetch::is_url(filename) {
+            let text = fetch::fetch(filename).await?;
+            manifests.extend(parse::parse(&text, filename)?);
+        } else {
+            let paths = files(Path::new(filename), recursive, true)
+                .map_err(|err| ManifestError::read(filename, err))?;
+            for path in paths {
+                let origin = path.display().to_string();
+                let text =
+                    fs::read_to_string(&path).map_err(|err| ManifestError::read(&origin, err))?;
+                manifests.extend(parse::parse(&text, &origin)?);
+            }
+        }
+    }
+
+    if manifests.is_empty() {
+        Err(ManifestError::Empty(filenames.join(", ")))
+    } else {
+        Ok(manifests)
+    }
+}
+
+/// The file itself, or the manifests in the directory, in a stable order
+fn files(path: &Path, recursive: bool, top: bool) -> io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    if !top && !recursive {
+        return Ok(vec![]);
+    }
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    let mut files = vec![];
+    for entry in entries {
+        if entry.is_dir() {
+            files.extend(self::files(&entry, recursive, false)?);
+        } else if is_manifest(&entry) {
+            files.push(entry);
+        }
+    }
+    Ok(files)
+}
+
+fn is_manifest(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("yaml" | "yml" | "json")
+    )
+}
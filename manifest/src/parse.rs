@@ -0,0 +1,198 @@
+use super::*;
+
+/// Objects in `text`, either a stream of JSON values or a multi-document YAML
+pub(crate) fn parse(text: &str, origin: &str) -> Result<Vec<Manifest>, ManifestError> {
+    let at = |line| Source {
+        origin: origin.to_string(),
+        line,
+    };
+
+    let mut manifests = vec![];
+    if text.trim_start().starts_with(['{', '[']) {
+        let mut values = json::Deserializer::from_str(text).into_iter::<json::Value>();
+        loop {
+            let offset = values.byte_offset();
+            let Some(value) = values.next() else {
+                break;
+            };
+            let value = value.map_err(|err| ManifestError::parse(at(err.line()), err))?;
+            let line = line_of(text, offset);
+            manifests.extend(objects(value, at(line))?);
+        }
+    } else {
+        for (first, document) in documents(text) {
+            let value = serde_yaml::from_str::<json::Value>(&document).map_err(|err| {
+                let line = err.location().map_or(first, |location| {
+                    first + location.line().saturating_sub(first_content(&document))
+                });
+                ManifestError::parse(at(line), err)
+            })?;
+            manifests.extend(objects(value, at(first))?);
+        }
+    }
+    Ok(manifests)
+}
+
+/// One object, or the items of a `List`
+fn objects(value: json::Value, at: Source) -> Result<Vec<Manifest>, ManifestError> {
+    if value.is_null() {
+        return Ok(vec![]);
+    }
+    let is_list = value
+        .get("kind")
+        .and_then(json::Value::as_str)
+        .is_some_and(|kind| kind.ends_with("List"))
+        && value.get("items").is_some_and(json::Value::is_array);
+    let values = match value {
+        json::Value::Object(mut list) if is_list => match list.remove("items") {
+            Some(json::Value::Array(items)) => items,
+            _ => vec![],
+        },
+        value => vec![value],
+    };
+
+    values
+        .into_iter()
+        .map(|value| {
+            json::from_value::<api::DynamicObject>(value)
+                .map(|object| Manifest {
+                    object,
+                    source: at.clone(),
+                })
+                .map_err(|err| ManifestError::parse(at.clone(), err))
+        })
+        .collect()
+}
+
+/// YAML documents with the line their content starts on, skipping the ones without any
+fn documents(text: &str) -> Vec<(usize, String)> {
+    let mut documents = vec![];
+    let mut document = String::new();
+    let mut start = 1;
+    for (index, line) in text.lines().enumerate() {
+        if is_separator(line) {
+            documents.push((start, std::mem::take(&mut document)));
+            start = index + 2;
+        } else {
+            document.push_str(line);
+            document.push('\n');
+        }
+    }
+    documents.push((start, document));
+
+    documents
+        .into_iter()
+        .filter(|(_, document)| !is_blank(document))
+        .map(|(start, document)| (start + first_content(&document) - 1, document))
+        .collect()
+}
+
+fn is_separator(line: &str) -> bool {
+    line.strip_prefix("---")
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t', '#']))
+}
+
+fn is_comment_or_blank(line: &str) -> bool {
+    let line = line.trim_start();
+    line.is_empty() || line.starts_with('#')
+}
+
+fn is_blank(document: &str) -> bool {
+    document.lines().all(is_comment_or_blank)
+}
+
+/// Line number, within the document, of its first line with content
+fn first_content(document: &str) -> usize {
+    document
+        .lines()
+        .position(|line| !is_comment_or_blank(line))
+        .map_or(1, |index| index + 1)
+}
+
+/// Line number of the first non-whitespace character from `offset` on
+fn line_of(text: &str, offset: usize) -> usize {
+    let rest = &text[offset..];
+    let skipped = rest.len() - rest.trim_start().len();
+    text[..offset + skipped].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(manifests: &[Manifest]) -> Vec<(String, usize)> {
+        manifests
+            .iter()
+            .map(|manifest| {
+                (
+                    manifest.object.metadata.name.clone().unwrap(),
+                    manifest.source.line,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn multiple_documents() {
+        let text = r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: first
+data:
+  key: value
+---
+---
+# second
+{"apiVersion": "apps/v1", "kind": "Deployment", "metadata": {"name": "second"}}
+"#;
+        let manifests = parse(text, "test.yaml").unwrap();
+        assert_eq!(
+            lines(&manifests),
+            [("first".to_string(), 2), ("second".to_string(), 11)]
+        );
+        assert_eq!(
+            manifests[0].object.types.as_ref().unwrap().kind,
+            "ConfigMap"
+        );
+        assert_eq!(
+            manifests[1].object.types.as_ref().unwrap().api_version,
+            "apps/v1"
+        );
+    }
+
+    #[test]
+    fn json_list() {
+        let text = r#"{
+  "apiVersion": "v1",
+  "kind": "List",
+  "items": [
+    {"apiVersion": "v1", "kind": "ConfigMap", "metadata": {"name": "first"}},
+    {"apiVersion": "v1", "kind": "Secret", "metadata": {"name": "second"}}
+  ]
+}
+
+{"apiVersion": "v1", "kind": "Service", "metadata": {"name": "third"}}
+"#;
+        let manifests = parse(text, "test.json").unwrap();
+        assert_eq!(
+            lines(&manifests),
+            [
+                ("first".to_string(), 1),
+                ("second".to_string(), 1),
+                ("third".to_string(), 10)
+            ]
+        );
+    }
+
+    #[test]
+    fn error_location() {
+        let text = "apiVersion: v1\nkind: ConfigMap\n---\n\napiVersion: v1\nkind: ]\n";
+        let err = parse(text, "bad.yaml").unwrap_err();
+        let ManifestError::Parse { at, .. } = err else {
+            panic!("expecting a parse error, found {err:?}");
+        };
+        assert_eq!(at.origin, "bad.yaml");
+        assert_eq!(at.line, 6, "{at}");
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+impl Resource {
+    /// Whether the cached discovery lists `verb` for this resource
+    pub fn supports(&self, kubeapi: &Kubeapi, verb: &str) -> bool {
+        self.discovered(kubeapi, None)
+            .is_some_and(|resource| resource.verbs.iter().any(|supported| supported == verb))
+    }
+
+    /// Deletes `objects` of this resource, as found by a `LIST` with the current selectors.
+    /// Every namespace is deleted as a collection when the server supports `deletecollection`,
+    /// taking along objects matching since the `LIST`, otherwise the objects are deleted one
+    /// by one. Returns the deleted objects as the server reported them, that may still be
+    /// around while terminating or held by finalizers.
+    pub async fn delete_selected(
+        &self,
+        kubeapi: &Kubeapi,
+        dp: &api::DeleteParams,
+        objects: &[api::DynamicObject],
+    ) -> kube::Result<Vec<api::DynamicObject>> {
+        if !self.supports(kubeapi, "deletecollection") {
+            return self.delete_objects(kubeapi, dp, objects).await;
+        }
+
+        let (scope, ref dyntype) = self.api_resource();
+        let namespaces = objects
+            .iter()
+            .map(|object| object.namespace())
+            .collect::<BTreeSet<_>>();
+        let lp = kubeapi.list_params();
+        let mut deleted = vec![];
+        for namespace in namespaces {
+            let api = kubeapi.object_api(scope.clone(), dyntype, namespace.as_deref())?;
+            if let Some(list) = api.delete_collection(dp, &lp).await?.left() {
+                deleted.extend(list.items);
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Deletes exactly `objects`, each only while it is still the same object, going by its uid.
+    /// Returns the deleted objects, as the server reported them when it did.
+    pub async fn delete_objects(
+        &self,
+        kubeapi: &Kubeapi,
+        dp: &api::DeleteParams,
+        objects: &[api::DynamicObject],
+    ) -> kube::Result<Vec<api::DynamicObject>> {
+        let (scope, ref dyntype) = self.api_resource();
+        let mut deleted = vec![];
+        for object in objects {
+            let namespace = object.namespace();
+            let api = kubeapi.object_api(scope.clone(), dyntype, namespace.as_deref())?;
+            let dp = api::DeleteParams {
+                preconditions: Some(api::Preconditions {
+                    uid: object.uid(),
+                    resource_version: None,
+                }),
+                ..dp.clone()
+            };
+            match api.delete(&object.name_any(), &dp).await {
+                Ok(reported) => deleted.push(reported.left().unwrap_or_else(|| object.clone())),
+                Err(kube::Error::Api(status)) if status.is_not_found() => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(deleted)
+    }
+}
@@ -1,23 +1,49 @@
+use std::collections::BTreeMap;
 use std::fmt;
 
 use k8s_openapi_ext as k8s;
+use kube::ResourceExt;
 use kube::api;
 use kube::discovery;
+use serde::Serialize;
+use serde_json as json;
 
 // use k8s::authenticationv1;
 // use k8s::authorizationv1;
 use k8s::corev1;
-// use k8s::metav1;
+use k8s::metav1;
 // use k8s::rbacv1;
 
 // use rkubectl_ext::APIResourceExt;
 use rkubectl_ext::APIResourceListExt;
 use rkubectl_kubeapi::Kubeapi;
+use rkubectl_manifest::Manifest;
+use rkubectl_manifest::ManifestError;
+use rkubectl_manifest::Source;
 use rkubectl_ui::Show;
 
+pub use merge::LAST_APPLIED_CONFIG;
+pub use merge::PatchStrategy;
+pub use merge::apply_patch;
+pub use merge::last_applied;
+pub use merge::save_config;
+pub use merge::three_way_patch;
+pub use mergekeys::MergeKeys;
 pub use named::NamedResource;
-
+pub use object::ManifestObject;
+pub use patch::patch_strategic;
+pub use select::SelectError;
+pub use watch::WatchStream;
+
+mod delete;
+mod merge;
+mod mergekeys;
 mod named;
+mod object;
+mod patch;
+mod scale;
+mod select;
+mod watch;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ResourceArg {
@@ -85,21 +111,36 @@ impl ResourceArg {
         }
     }
 
-    pub async fn delete(
+    /// Same as `get()`, but lets the server render the table columns
+    pub async fn get_table(&self, kubeapi: &Kubeapi) -> kube::Result<Box<dyn Show>> {
+        let (scope, ref dyntype) = self.resource().api_resource();
+        let table = match self {
+            Self::Resource(_resource) => kubeapi.list_table(scope, dyntype).await?,
+            Self::NamedResource(named_resource) => {
+                kubeapi
+                    .get_table(scope, dyntype, named_resource.name())
+                    .await?
+            }
+        };
+        Ok(Box::new(table))
+    }
+
+    /// Same as `get()`, but always returns plain `DynamicObject`s with their types filled in
+    pub async fn dynamic_objects(
         &self,
         kubeapi: &Kubeapi,
-        dp: &api::DeleteParams,
-        all: bool,
-    ) -> kube::Result<()> {
-        match self {
-            Self::Resource(resource) if all => {
-                todo!("Deleting ALL resources {resource:?} is not implemented yet")
-            }
-            Self::Resource(resource) => {
-                todo!("Deleting SOME resources {resource:?} is not implemented yet")
-            }
-            Self::NamedResource(resource) => resource.delete(kubeapi, dp).await,
-        }
+    ) -> kube::Result<Vec<api::DynamicObject>> {
+        let (scope, ref dyntype) = self.resource().api_resource();
+        let api = kubeapi.dynamic_object_api(scope, dyntype)?;
+        let mut objects = match self {
+            Self::Resource(_resource) => api.list(&kubeapi.list_params()).await?.items,
+            Self::NamedResource(named_resource) => vec![api.get(named_resource.name()).await?],
+        };
+        objects
+            .iter_mut()
+            .filter(|object| object.types.is_none())
+            .for_each(|object| object.types = Some(Resource::type_meta(dyntype)));
+        Ok(objects)
     }
 
     pub fn resource(&self) -> &Resource {
@@ -179,7 +220,16 @@ impl Resource {
                 Ok(Box::new(list))
             }
             Self::Other { scope, resource } => {
-                todo!("list not implemented yet for {scope:?} {resource:?}")
+                let mut list = kubeapi
+                    .dynamic_object_api(scope.clone(), resource)?
+                    .list(&lp)
+                    .await?;
+                // List items come without apiVersion/kind, restore them from discovery
+                list.items
+                    .iter_mut()
+                    .filter(|object| object.types.is_none())
+                    .for_each(|object| object.types = Some(Self::type_meta(resource)));
+                Ok(Box::new(list))
             }
         }
     }
@@ -207,7 +257,11 @@ impl Resource {
                 Ok(Box::new(obj))
             }
             Self::Other { scope, resource } => {
-                todo!("get not implemented yet for {scope:?} {resource:?}")
+                let obj = kubeapi
+                    .dynamic_object_api(scope.clone(), resource)?
+                    .get(name)
+                    .await?;
+                Ok(Box::new(obj))
             }
         }
     }
@@ -240,6 +294,12 @@ impl Resource {
     //     Ok(())
     // }
 
+    /// Same as kubectl in the results of operations, `kind.group`, e.g. `deployment.apps`
+    pub fn qualified_kind(&self) -> String {
+        let (_scope, dyntype) = self.api_resource();
+        qualified_kind(&dyntype)
+    }
+
     pub fn api_resource(&self) -> (discovery::Scope, api::ApiResource) {
         use discovery::Scope::{Cluster, Namespaced};
 
@@ -263,6 +323,32 @@ impl Resource {
             .find_map(|arl| arl.kube_api_resource(name))
     }
 
+    /// This resource, or its `subresource`, as listed by the cached discovery
+    fn discovered(
+        &self,
+        kubeapi: &Kubeapi,
+        subresource: Option<&str>,
+    ) -> Option<metav1::APIResource> {
+        let (_scope, dyntype) = self.api_resource();
+        let name = match subresource {
+            Some(subresource) => format!("{}/{subresource}", dyntype.plural),
+            None => dyntype.plural,
+        };
+        kubeapi
+            .cached_server_api_resources()
+            .into_iter()
+            .filter(|arl| arl.group_version == dyntype.api_version)
+            .flat_map(|arl| arl.resources)
+            .find(|resource| resource.name == name)
+    }
+
+    fn type_meta(resource: &api::ApiResource) -> api::TypeMeta {
+        api::TypeMeta {
+            api_version: resource.api_version.clone(),
+            kind: resource.kind.clone(),
+        }
+    }
+
     fn erase<K>() -> api::ApiResource
     where
         K: kube::Resource,
@@ -290,6 +376,16 @@ impl fmt::Display for Resource {
     }
 }
 
+/// The lowercase kind, qualified with the group unless it is the core group
+pub fn qualified_kind(resource: &api::ApiResource) -> String {
+    let kind = resource.kind.to_lowercase();
+    if resource.group.is_empty() {
+        kind
+    } else {
+        format!("{kind}.{}", resource.group)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(
     "there is no need to specify a resource type as a separate argument when passing arguments in resource/name form (e.g. 'kubectl get resource/<resource_name>' instead of 'kubectl get resource resource/<resource_name>')"
@@ -386,6 +482,16 @@ mod tests {
         assert_eq!(node.name(), "elephant");
     }
 
+    #[test]
+    fn qualified_kinds() {
+        assert_eq!(Resource::Pods.qualified_kind(), "pod");
+        let deployments = Resource::Other {
+            scope: discovery::Scope::Namespaced,
+            resource: Resource::erase::<k8s::appsv1::Deployment>(),
+        };
+        assert_eq!(deployments.qualified_kind(), "deployment.apps");
+    }
+
     #[test]
     fn invalid_mix() {
         let _err = args(&["pod/bazooka", "node"]).unwrap_err();
@@ -0,0 +1,505 @@
+use crate::mergekeys::ListMerge;
+
+use super::*;
+
+/// Annotation holding the configuration last applied with client-side apply
+pub const LAST_APPLIED_CONFIG: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// Prefix of the strategic merge patch directive listing the items to remove from a primitive list
+const DELETE_FROM_PRIMITIVE_LIST: &str = "$deleteFromPrimitiveList/";
+
+type Object = json::Map<String, json::Value>;
+
+/// How lists are handled when patching
+#[derive(Clone, Copy, Debug)]
+pub enum PatchStrategy<'a> {
+    /// Strategic merge patch, lists with a merge key are patched item by item
+    Strategic(&'a MergeKeys),
+    /// JSON merge patch, lists are always replaced as a whole
+    Merge,
+}
+
+/// Configuration recorded by the last client-side apply of `object`, if any
+pub fn last_applied<K>(object: &K) -> Option<json::Value>
+where
+    K: kube::Resource,
+{
+    object
+        .meta()
+        .annotations
+        .as_ref()?
+        .get(LAST_APPLIED_CONFIG)
+        .and_then(|config| json::from_str(config).ok())
+}
+
+/// Records `object`, minus any previous record, as its own last applied configuration
+pub fn save_config<K>(object: &mut K) -> json::Result<()>
+where
+    K: kube::Resource + Serialize,
+{
+    let annotations = &mut object.meta_mut().annotations;
+    if let Some(map) = annotations {
+        map.remove(LAST_APPLIED_CONFIG);
+        if map.is_empty() {
+            *annotations = None;
+        }
+    }
+    let config = json::to_string(object)?;
+    object
+        .annotations_mut()
+        .insert(LAST_APPLIED_CONFIG.to_string(), config);
+    Ok(())
+}
+
+/// Same as `kubectl apply`, the patch taking `current` to `modified`, deleting what was dropped
+/// since `original` was applied and leaving the fields set by anyone else untouched
+pub fn three_way_patch(
+    original: Option<&json::Value>,
+    modified: &json::Value,
+    current: &json::Value,
+    strategy: PatchStrategy<'_>,
+) -> json::Value {
+    let (Some(modified), Some(current)) = (modified.as_object(), current.as_object()) else {
+        return modified.clone();
+    };
+    let empty = Object::new();
+    let original = original.and_then(json::Value::as_object).unwrap_or(&empty);
+
+    let deletions = Diff {
+        strategy,
+        changes: false,
+        deletions: true,
+    }
+    .map(original, modified, &[]);
+    let changes = Diff {
+        strategy,
+        changes: true,
+        deletions: false,
+    }
+    .map(current, modified, &[]);
+    json::Value::Object(merge(strategy, deletions, changes, &[]))
+}
+
+/// `target` as the server would leave it after `patch`, a patch as made by `three_way_patch()`
+/// or given by the user. With `PatchStrategy::Merge` it is a JSON merge patch (RFC 7386).
+/// The `$patch: replace`, `$patch: delete` and `$deleteFromPrimitiveList` directives of strategic
+/// merge patches are honored, other directives are ignored.
+pub fn apply_patch(
+    target: &json::Value,
+    patch: &json::Value,
+    strategy: PatchStrategy<'_>,
+) -> json::Value {
+    match (target, patch) {
+        (json::Value::Object(target), json::Value::Object(patch)) => {
+            let mut target = target.clone();
+            patch_object(strategy, &mut target, patch, &[]);
+            json::Value::Object(target)
+        }
+        _ => patch.clone(),
+    }
+}
+
+fn patch_object(strategy: PatchStrategy<'_>, target: &mut Object, patch: &Object, path: &[&str]) {
+    if patch.get("$patch").and_then(json::Value::as_str) == Some("replace") {
+        target.clear();
+    }
+    for (key, value) in patch {
+        if let (Some(field), json::Value::Array(deleted)) =
+            (key.strip_prefix(DELETE_FROM_PRIMITIVE_LIST), value)
+            && let Some(json::Value::Array(list)) = target.get_mut(field)
+        {
+            list.retain(|item| !deleted.contains(item));
+        }
+    }
+    for (key, value) in patch.iter().filter(|(key, _)| !key.starts_with('$')) {
+        let path = [path, &[key.as_str()]].concat();
+        match value {
+            json::Value::Null => {
+                target.remove(key);
+            }
+            json::Value::Object(patch) if patch.get("$patch") == Some(&"delete".into()) => {
+                target.remove(key);
+            }
+            json::Value::Object(patch) => {
+                let mut object = match target.remove(key) {
+                    Some(json::Value::Object(object)) => object,
+                    _ => Object::new(),
+                };
+                patch_object(strategy, &mut object, patch, &path);
+                target.insert(key.clone(), json::Value::Object(object));
+            }
+            json::Value::Array(patch) => {
+                let mut list = match (merge_key(strategy, &path), target.remove(key)) {
+                    (Some(_), Some(json::Value::Array(list))) => list,
+                    _ => vec![],
+                };
+                patch_list(strategy, &mut list, patch, &path);
+                target.insert(key.clone(), json::Value::Array(list));
+            }
+            value => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Items of keyed lists are patched by their key, primitives are added unless already there,
+/// others are added
+fn patch_list(
+    strategy: PatchStrategy<'_>,
+    list: &mut Vec<json::Value>,
+    patch: &[json::Value],
+    path: &[&str],
+) {
+    let merge = merge_key(strategy, path);
+    let key = merge.and_then(ListMerge::key);
+    for item in patch {
+        if merge == Some(ListMerge::Primitive) {
+            if !list.contains(item) {
+                list.push(item.clone());
+            }
+            continue;
+        }
+        let existing = key.and_then(|key| {
+            let id = item.get(key)?;
+            list.iter().position(|old| old.get(key) == Some(id))
+        });
+        match (item, existing) {
+            (json::Value::Object(item), existing)
+                if item.get("$patch") == Some(&"delete".into()) =>
+            {
+                if let Some(index) = existing {
+                    list.remove(index);
+                }
+            }
+            (json::Value::Object(item), Some(index)) => {
+                if let json::Value::Object(old) = &mut list[index] {
+                    patch_object(strategy, old, item, path);
+                }
+            }
+            (json::Value::Object(item), None) => {
+                let mut new = Object::new();
+                patch_object(strategy, &mut new, item, path);
+                list.push(json::Value::Object(new));
+            }
+            (item, _) => list.push(item.clone()),
+        }
+    }
+}
+
+struct Diff<'a> {
+    strategy: PatchStrategy<'a>,
+    changes: bool,
+    deletions: bool,
+}
+
+impl<'a> Diff<'a> {
+    fn map(&self, from: &Object, to: &Object, path: &[&str]) -> Object {
+        let mut patch = Object::new();
+        for (key, to_value) in to {
+            let Some(from_value) = from.get(key) else {
+                if self.changes {
+                    patch.insert(key.clone(), to_value.clone());
+                }
+                continue;
+            };
+            if from_value == to_value {
+                continue;
+            }
+            let path = [path, &[key.as_str()]].concat();
+            let value = match (from_value, to_value) {
+                (json::Value::Object(from), json::Value::Object(to)) => {
+                    let patch = self.map(from, to, &path);
+                    (!patch.is_empty()).then_some(json::Value::Object(patch))
+                }
+                (json::Value::Array(from), json::Value::Array(to))
+                    if self.merge_key(from, to, &path) == Some(ListMerge::Primitive) =>
+                {
+                    let deleted = difference(from, to);
+                    if self.deletions && !deleted.is_empty() {
+                        let directive = format!("{DELETE_FROM_PRIMITIVE_LIST}{key}");
+                        patch.insert(directive, json::Value::Array(deleted));
+                    }
+                    let added = difference(to, from);
+                    (self.changes && !added.is_empty()).then_some(json::Value::Array(added))
+                }
+                (json::Value::Array(from), json::Value::Array(to)) => self.list(from, to, &path),
+                _ => self.changes.then(|| to_value.clone()),
+            };
+            if let Some(value) = value {
+                patch.insert(key.clone(), value);
+            }
+        }
+
+        if self.deletions {
+            for key in from.keys().filter(|key| !to.contains_key(*key)) {
+                patch.insert(key.clone(), json::Value::Null);
+            }
+        }
+        patch
+    }
+
+    fn list(&self, from: &[json::Value], to: &[json::Value], path: &[&str]) -> Option<json::Value> {
+        let Some(key) = self.merge_key(from, to, path).and_then(ListMerge::key) else {
+            return self.changes.then(|| json::Value::Array(to.to_vec()));
+        };
+
+        let mut patch = vec![];
+        for item in to {
+            let id = &item[key];
+            match find(from, key, id) {
+                None if self.changes => patch.push(item.clone()),
+                None => {}
+                Some(old) if old == item => {}
+                Some(old) => {
+                    let (Some(old), Some(new)) = (old.as_object(), item.as_object()) else {
+                        continue;
+                    };
+                    let mut item = self.map(old, new, path);
+                    if !item.is_empty() {
+                        item.insert(key.to_string(), id.clone());
+                        patch.push(json::Value::Object(item));
+                    }
+                }
+            }
+        }
+
+        if self.deletions {
+            for item in from {
+                let id = &item[key];
+                if find(to, key, id).is_none() {
+                    patch.push(json::json!({"$patch": "delete", key: id}));
+                }
+            }
+        }
+        (!patch.is_empty()).then_some(json::Value::Array(patch))
+    }
+
+    /// Only usable when every item is an object carrying the key, or a primitive
+    fn merge_key(
+        &self,
+        from: &[json::Value],
+        to: &[json::Value],
+        path: &[&str],
+    ) -> Option<ListMerge<'a>> {
+        let merge = merge_key(self.strategy, path)?;
+        let usable = |item: &json::Value| match merge {
+            ListMerge::Key(key) => item.get(key).is_some(),
+            ListMerge::Primitive => !item.is_object() && !item.is_array(),
+        };
+        from.iter().chain(to).all(usable).then_some(merge)
+    }
+}
+
+/// Items of `items` missing from `other`
+fn difference(items: &[json::Value], other: &[json::Value]) -> Vec<json::Value> {
+    items
+        .iter()
+        .filter(|item| !other.contains(item))
+        .cloned()
+        .collect()
+}
+
+fn find<'a>(items: &'a [json::Value], key: &str, id: &json::Value) -> Option<&'a json::Value> {
+    items.iter().find(|item| item.get(key) == Some(id))
+}
+
+/// Combines the deletions with the changes, items of keyed lists are merged by their key
+fn merge(strategy: PatchStrategy<'_>, mut patch: Object, other: Object, path: &[&str]) -> Object {
+    for (key, value) in other {
+        let path = [path, &[key.as_str()]].concat();
+        let value = match (patch.remove(&key), value) {
+            (Some(json::Value::Object(first)), json::Value::Object(second)) => {
+                json::Value::Object(merge(strategy, first, second, &path))
+            }
+            (Some(json::Value::Array(mut first)), json::Value::Array(second)) => {
+                match merge_key(strategy, &path) {
+                    Some(ListMerge::Key(merge_key)) => {
+                        for item in second {
+                            let existing = first
+                                .iter_mut()
+                                .find(|existing| existing.get(merge_key) == item.get(merge_key));
+                            match (existing, item) {
+                                (
+                                    Some(json::Value::Object(existing)),
+                                    json::Value::Object(item),
+                                ) => {
+                                    let merged = merge(strategy, existing.clone(), item, &path);
+                                    *existing = merged;
+                                }
+                                (_, item) => first.push(item),
+                            }
+                        }
+                        json::Value::Array(first)
+                    }
+                    Some(ListMerge::Primitive) => {
+                        first.extend(difference(&second, &first));
+                        json::Value::Array(first)
+                    }
+                    None => json::Value::Array(second),
+                }
+            }
+            (_, value) => value,
+        };
+        patch.insert(key, value);
+    }
+    patch
+}
+
+fn merge_key<'a>(strategy: PatchStrategy<'a>, path: &[&str]) -> Option<ListMerge<'a>> {
+    match strategy {
+        PatchStrategy::Strategic(keys) => keys.merge_key(path),
+        PatchStrategy::Merge => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s::NamespaceExt;
+
+    use super::*;
+    use crate::mergekeys::tests::pod_merge_keys;
+
+    fn pod(image: &str, env: json::Value) -> json::Value {
+        json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": {"name": "web"},
+            "spec": {"containers": [{"name": "web", "image": image, "env": env}]},
+        })
+    }
+
+    #[test]
+    fn keeps_fields_set_by_others() {
+        let original = pod("nginx:1", json::json!([]));
+        let modified = pod("nginx:2", json::json!([]));
+        let mut current = pod("nginx:1", json::json!([]));
+        current["spec"]["nodeName"] = "node-1".into();
+        current["metadata"]["resourceVersion"] = "42".into();
+
+        let keys = pod_merge_keys();
+        let patch = three_way_patch(
+            Some(&original),
+            &modified,
+            &current,
+            PatchStrategy::Strategic(&keys),
+        );
+        assert_eq!(
+            patch,
+            json::json!({"spec": {"containers": [{"name": "web", "image": "nginx:2"}]}})
+        );
+    }
+
+    #[test]
+    fn deletes_what_was_dropped() {
+        let original = pod("nginx", json::json!([{"name": "A", "value": "1"}]));
+        let mut modified = pod("nginx", json::json!([{"name": "B", "value": "2"}]));
+        modified["metadata"]["labels"] = json::json!({"app": "web"});
+        let mut current = original.clone();
+        current["metadata"]["labels"] = json::json!({"app": "old", "team": "a"});
+
+        let keys = pod_merge_keys();
+        let patch = three_way_patch(
+            Some(&original),
+            &modified,
+            &current,
+            PatchStrategy::Strategic(&keys),
+        );
+        assert_eq!(
+            patch,
+            json::json!({
+                "metadata": {"labels": {"app": "web"}},
+                "spec": {"containers": [{
+                    "name": "web",
+                    "env": [{"$patch": "delete", "name": "A"}, {"name": "B", "value": "2"}],
+                }]},
+            })
+        );
+
+        let patch = three_way_patch(Some(&original), &modified, &current, PatchStrategy::Merge);
+        assert_eq!(
+            patch["spec"]["containers"][0]["env"],
+            json::json!([{"name": "B", "value": "2"}])
+        );
+    }
+
+    #[test]
+    fn deletes_dropped_finalizers() {
+        let mut original = pod("nginx", json::json!([]));
+        original["metadata"]["finalizers"] = json::json!(["a", "b"]);
+        let mut modified = original.clone();
+        modified["metadata"]["finalizers"] = json::json!(["a", "c"]);
+        let mut live = original.clone();
+        live["metadata"]["finalizers"] = json::json!(["a", "b", "d"]);
+
+        let keys = pod_merge_keys();
+        let strategy = PatchStrategy::Strategic(&keys);
+        let patch = three_way_patch(Some(&original), &modified, &live, strategy);
+        assert_eq!(
+            patch,
+            json::json!({"metadata": {
+                "$deleteFromPrimitiveList/finalizers": ["b"],
+                "finalizers": ["c"],
+            }})
+        );
+
+        // The server takes the union, minus what is deleted
+        let patched = apply_patch(&live, &patch, strategy);
+        assert_eq!(
+            patched["metadata"]["finalizers"],
+            json::json!(["a", "d", "c"])
+        );
+    }
+
+    #[test]
+    fn applies_patches_locally() {
+        let keys = pod_merge_keys();
+        let original = pod("nginx:1", json::json!([{"name": "A", "value": "1"}]));
+        let mut modified = pod("nginx:2", json::json!([{"name": "B", "value": "2"}]));
+        modified["metadata"]["labels"] = json::json!({"app": "web"});
+        let mut live = original.clone();
+        live["spec"]["nodeName"] = "node-1".into();
+        live["spec"]["containers"][0]["env"]
+            .as_array_mut()
+            .unwrap()
+            .push(json::json!({"name": "C", "value": "3"}));
+        live["spec"]["containers"]
+            .as_array_mut()
+            .unwrap()
+            .push(json::json!({"name": "sidecar", "image": "envoy"}));
+
+        let strategy = PatchStrategy::Strategic(&keys);
+        let patch = three_way_patch(Some(&original), &modified, &live, strategy);
+        let patched = apply_patch(&live, &patch, strategy);
+        assert_eq!(patched["metadata"]["labels"], json::json!({"app": "web"}));
+        assert_eq!(patched["spec"]["nodeName"], "node-1");
+        assert_eq!(
+            patched["spec"]["containers"],
+            json::json!([
+                {
+                    "name": "web",
+                    "image": "nginx:2",
+                    "env": [{"name": "C", "value": "3"}, {"name": "B", "value": "2"}],
+                },
+                {"name": "sidecar", "image": "envoy"},
+            ])
+        );
+
+        // Lists are replaced as a whole by merge patches
+        let patch = json::json!({"spec": {"containers": [{"name": "web"}]}});
+        let patched = apply_patch(&live, &patch, PatchStrategy::Merge);
+        assert_eq!(
+            patched["spec"]["containers"],
+            json::json!([{"name": "web"}])
+        );
+    }
+
+    #[test]
+    fn saved_config_omits_itself() {
+        let mut ns = corev1::Namespace::new("web");
+        save_config(&mut ns).unwrap();
+        save_config(&mut ns).unwrap();
+        let config = last_applied(&ns).unwrap();
+        assert_eq!(config["metadata"], json::json!({"name": "web"}));
+    }
+}
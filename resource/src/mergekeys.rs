@@ -0,0 +1,202 @@
+use super::*;
+
+/// Patch merge keys of the lists in a kind, as declared by `x-kubernetes-patch-merge-key`
+/// alongside `x-kubernetes-patch-strategy: merge` in its OpenAPI v3 schema.
+/// Lists with the strategy but no key, e.g. `metadata.finalizers`, are sets of primitives.
+///
+/// Lists without a strategy, including those of custom resources, are atomic and replaced as a whole.
+#[derive(Clone, Debug, Default)]
+pub struct MergeKeys {
+    document: json::Value,
+    /// The name of the kind's schema under `components.schemas`
+    kind: Option<String>,
+}
+
+impl MergeKeys {
+    /// The merge keys of `kind` in `api_version`, none when the server publishes no OpenAPI v3 schema
+    pub async fn new(kubeapi: &Kubeapi, api_version: &str, kind: &str) -> Self {
+        let (group, version) = api_version.rsplit_once('/').unwrap_or(("", api_version));
+        match kubeapi.openapi_v3(api_version).await {
+            Ok(Some(document)) => Self::from_document(document, group, version, kind),
+            Ok(None) | Err(_) => Self::default(),
+        }
+    }
+
+    /// The merge keys of `kind`, going by the `x-kubernetes-group-version-kind` extension
+    pub fn from_document(document: json::Value, group: &str, version: &str, kind: &str) -> Self {
+        let is_kind = |gvk: &json::Value| {
+            gvk["group"] == group && gvk["version"] == version && gvk["kind"] == kind
+        };
+        let kind = document["components"]["schemas"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .find(|(_, schema)| {
+                schema["x-kubernetes-group-version-kind"]
+                    .as_array()
+                    .is_some_and(|gvks| gvks.iter().any(is_kind))
+            })
+            .map(|(name, _)| name.clone());
+        Self { document, kind }
+    }
+
+    /// How the list at `path` is merged, field names from the top of the object,
+    /// looking through the items of lists and the values of maps on the way
+    pub(crate) fn merge_key(&self, path: &[&str]) -> Option<ListMerge<'_>> {
+        let mut field = self.schemas().get(self.kind.as_deref()?)?;
+        for name in path {
+            let element = self.element(field);
+            field = element
+                .get("properties")
+                .and_then(|properties| properties.get(*name))
+                .or_else(|| {
+                    element
+                        .get("additionalProperties")
+                        .filter(|values| values.is_object())
+                })?;
+        }
+        let merged = field["x-kubernetes-patch-strategy"]
+            .as_str()?
+            .split(',')
+            .any(|strategy| strategy == "merge");
+        let merge = match field["x-kubernetes-patch-merge-key"].as_str() {
+            Some(key) => ListMerge::Key(key),
+            None => ListMerge::Primitive,
+        };
+        merged.then_some(merge)
+    }
+
+    fn schemas(&self) -> &json::Value {
+        &self.document["components"]["schemas"]
+    }
+
+    /// The schema of the items of lists, or `field` itself, with `$ref`s followed
+    fn element<'a>(&'a self, field: &'a json::Value) -> &'a json::Value {
+        let schema = self.resolve(field);
+        match schema.get("items") {
+            Some(items) if schema["type"] == "array" => self.element(items),
+            _ => schema,
+        }
+    }
+
+    /// Follows `$ref`, also when wrapped in a single `allOf` as done for fields with defaults
+    fn resolve<'a>(&'a self, field: &'a json::Value) -> &'a json::Value {
+        let target =
+            field["$ref"]
+                .as_str()
+                .or_else(|| match field["allOf"].as_array().map(Vec::as_slice) {
+                    Some([single]) => single["$ref"].as_str(),
+                    _ => None,
+                });
+        target
+            .and_then(|target| target.strip_prefix("#/components/schemas/"))
+            .and_then(|name| self.schemas().get(name))
+            .unwrap_or(field)
+    }
+}
+
+/// How strategic merge patches merge the items of a list
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ListMerge<'a> {
+    /// Objects, patched item by item by the value of this field
+    Key(&'a str),
+    /// Primitives, the list being the union of the patch and the object,
+    /// with removals listed under `$deleteFromPrimitiveList/<field>`
+    Primitive,
+}
+
+impl<'a> ListMerge<'a> {
+    pub(crate) fn key(self) -> Option<&'a str> {
+        match self {
+            Self::Key(key) => Some(key),
+            Self::Primitive => None,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A trimmed down `api/v1` document, with the merge keys of pod containers and their env,
+    /// and the finalizers merged as primitives
+    pub(crate) fn pod_merge_keys() -> MergeKeys {
+        let document = json::json!({
+            "components": {
+                "schemas": {
+                    "io.k8s.api.core.v1.Pod": {
+                        "properties": {
+                            "metadata": {"allOf": [{"$ref": "#/components/schemas/io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta"}]},
+                            "spec": {"allOf": [{"$ref": "#/components/schemas/io.k8s.api.core.v1.PodSpec"}]}
+                        },
+                        "x-kubernetes-group-version-kind": [{"group": "", "kind": "Pod", "version": "v1"}]
+                    },
+                    "io.k8s.api.core.v1.PodSpec": {
+                        "properties": {
+                            "containers": {
+                                "items": {"allOf": [{"$ref": "#/components/schemas/io.k8s.api.core.v1.Container"}]},
+                                "type": "array",
+                                "x-kubernetes-patch-merge-key": "name",
+                                "x-kubernetes-patch-strategy": "merge"
+                            },
+                            "readinessGates": {
+                                "items": {"type": "object"},
+                                "type": "array",
+                                "x-kubernetes-list-type": "atomic"
+                            }
+                        }
+                    },
+                    "io.k8s.api.core.v1.Container": {
+                        "properties": {
+                            "env": {
+                                "items": {"$ref": "#/components/schemas/io.k8s.api.core.v1.EnvVar"},
+                                "type": "array",
+                                "x-kubernetes-patch-merge-key": "name",
+                                "x-kubernetes-patch-strategy": "merge"
+                            },
+                            "ports": {
+                                "items": {"type": "object"},
+                                "type": "array",
+                                "x-kubernetes-list-map-keys": ["containerPort", "protocol"],
+                                "x-kubernetes-patch-merge-key": "containerPort",
+                                "x-kubernetes-patch-strategy": "merge"
+                            }
+                        }
+                    },
+                    "io.k8s.api.core.v1.EnvVar": {"properties": {"name": {"type": "string"}}},
+                    "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {
+                        "properties": {
+                            "finalizers": {
+                                "items": {"type": "string"},
+                                "type": "array",
+                                "x-kubernetes-list-type": "set",
+                                "x-kubernetes-patch-strategy": "merge"
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        MergeKeys::from_document(document, "", "v1", "Pod")
+    }
+
+    #[test]
+    fn from_schema() {
+        let keys = pod_merge_keys();
+        let key = |path: &[&str]| keys.merge_key(path).and_then(ListMerge::key);
+        assert_eq!(key(&["spec", "containers"]), Some("name"));
+        assert_eq!(key(&["spec", "containers", "env"]), Some("name"));
+        assert_eq!(key(&["spec", "containers", "ports"]), Some("containerPort"));
+        assert_eq!(
+            keys.merge_key(&["metadata", "finalizers"]),
+            Some(ListMerge::Primitive)
+        );
+        // Atomic lists, unknown fields and kinds
+        assert_eq!(keys.merge_key(&["spec", "readinessGates"]), None);
+        assert_eq!(keys.merge_key(&["spec", "ports"]), None);
+        assert_eq!(
+            MergeKeys::default().merge_key(&["spec", "containers"]),
+            None
+        );
+    }
+}
@@ -20,18 +20,42 @@ impl NamedResource {
         &self.name
     }
 
-    pub async fn delete(&self, kubeapi: &Kubeapi, dp: &api::DeleteParams) -> kube::Result<()> {
+    /// Pods backing this object, i.e. the pod itself, or the pods matched by the object's
+    /// selector for workloads (deployments, jobs, statefulsets, ..) and services.
+    pub async fn pods(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<corev1::Pod>> {
+        if self.resource == Resource::Pods {
+            let pod = kubeapi.pods()?.get(&self.name).await?;
+            return Ok(vec![pod]);
+        }
+
+        let (scope, ref dyntype) = self.resource.api_resource();
+        let object = kubeapi
+            .dynamic_object_api(scope, dyntype)?
+            .get(&self.name)
+            .await?;
+        let Some(selector) = pod_selector(&object) else {
+            return Ok(vec![]);
+        };
+        let namespace = object.namespace().unwrap_or_default();
+        let lp = api::ListParams::default().labels_from(&selector);
+        let mut pods = kubeapi.namespaced_pods(&namespace)?.list(&lp).await?.items;
+        pods.sort_by_key(|pod| pod.creation_timestamp());
+        Ok(pods)
+    }
+
+    /// Deletes the object, returning it when it is still around, e.g. terminating or held by finalizers
+    pub async fn delete(
+        &self,
+        kubeapi: &Kubeapi,
+        dp: &api::DeleteParams,
+    ) -> kube::Result<Option<api::DynamicObject>> {
         let (scope, ref dyntype) = self.resource.api_resource();
-        kubeapi
+        let deleted = kubeapi
             .dynamic_object_api(scope, dyntype)?
             .delete(&self.name, dp)
-            .await
-            .inspect_err(|err| eprintln!("{err:?}"))?
-            .either(
-                |object| println!("{object:?}"),
-                |status| println!("{status:?}"),
-            );
-        Ok(())
+            .await?
+            .left();
+        Ok(deleted)
     }
 }
 
@@ -40,3 +64,19 @@ impl fmt::Display for NamedResource {
         format_args!("{}/{}", self.resource, self.name).fmt(f)
     }
 }
+
+/// Workloads carry a full `LabelSelector` in `spec.selector`,
+/// while services and replication controllers use a plain label map.
+fn pod_selector(object: &api::DynamicObject) -> Option<kube::core::Selector> {
+    let selector = object.data.get("spec")?.get("selector")?.clone();
+    if selector.get("matchLabels").is_some() || selector.get("matchExpressions").is_some() {
+        serde_json::from_value::<metav1::LabelSelector>(selector)
+            .ok()
+            .and_then(|selector| selector.try_into().ok())
+    } else {
+        serde_json::from_value::<BTreeMap<String, String>>(selector)
+            .ok()
+            .filter(|labels| !labels.is_empty())
+            .map(|labels| labels.into_iter().collect())
+    }
+}
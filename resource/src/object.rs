@@ -0,0 +1,172 @@
+use super::*;
+
+/// Object read from a manifest, along with its API resource as found through discovery
+#[derive(Clone, Debug)]
+pub struct ManifestObject {
+    object: api::DynamicObject,
+    source: Source,
+    scope: discovery::Scope,
+    resource: api::ApiResource,
+}
+
+impl ManifestObject {
+    /// Resolves the object's `apiVersion` and `kind` through the discovery cache,
+    /// asking the server when they are not cached (e.g. CRDs installed since).
+    pub async fn new(kubeapi: &Kubeapi, manifest: Manifest) -> Result<Self, ManifestError> {
+        let Manifest { object, source } = manifest;
+        let (scope, resource) = Self::discover(kubeapi, &object)
+            .await
+            .map_err(|err| ManifestError::object(source.clone(), err))?;
+        Ok(Self {
+            object,
+            source,
+            scope,
+            resource,
+        })
+    }
+
+    async fn discover(
+        kubeapi: &Kubeapi,
+        object: &api::DynamicObject,
+    ) -> kube::Result<(discovery::Scope, api::ApiResource)> {
+        let types = object.types.clone().unwrap_or_default();
+        if types.api_version.is_empty() || types.kind.is_empty() {
+            let name = object.name_any();
+            return Err(kube::Error::Discovery(
+                kube::error::DiscoveryError::MissingKind(format!(
+                    "object '{name}' does not specify apiVersion and kind"
+                )),
+            ));
+        }
+
+        let cached = kubeapi
+            .cached_server_api_resources()
+            .into_iter()
+            .find_map(|arl| arl.kube_api_resource_for(&types.api_version, &types.kind));
+        match cached {
+            Some(cached) => Ok(cached),
+            None => {
+                let gvk = kube::core::GroupVersionKind::try_from(&types).map_err(|err| {
+                    kube::Error::Discovery(kube::error::DiscoveryError::InvalidGroupVersion(
+                        err.to_string(),
+                    ))
+                })?;
+                let client = kubeapi.client()?;
+                let (resource, capabilities) = discovery::pinned_kind(&client, &gvk).await?;
+                Ok((capabilities.scope, resource))
+            }
+        }
+    }
+
+    pub fn object(&self) -> &api::DynamicObject {
+        &self.object
+    }
+
+    pub fn into_object(self) -> api::DynamicObject {
+        self.object
+    }
+
+    /// Where the object was read from
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// `error` while handling the object, attributed to its source
+    pub fn error(&self, error: kube::Error) -> ManifestError {
+        ManifestError::object(self.source.clone(), error)
+    }
+
+    pub fn name(&self) -> String {
+        self.object.name_any()
+    }
+
+    pub fn resource(&self) -> &api::ApiResource {
+        &self.resource
+    }
+
+    /// Same as kubectl, `kind.group`, e.g. `deployment.apps`
+    pub fn kind(&self) -> String {
+        qualified_kind(&self.resource)
+    }
+
+    pub fn is_namespaced(&self) -> bool {
+        self.scope == discovery::Scope::Namespaced
+    }
+
+    /// API to manage the object with, in its own namespace if it names one
+    pub fn api(&self, kubeapi: &Kubeapi) -> Result<api::Api<api::DynamicObject>, ManifestError> {
+        let namespace = self.namespace(kubeapi)?;
+        kubeapi
+            .object_api(self.scope.clone(), &self.resource, namespace)
+            .map_err(|err| self.error(err))
+    }
+
+    /// The namespace the object names, that has to match the one given with `--namespace`, if any,
+    /// same as kubectl
+    fn namespace(&self, kubeapi: &Kubeapi) -> Result<Option<&str>, ManifestError> {
+        let namespace = self.object.metadata.namespace.as_deref();
+        if let (Some(object), Some(namespace)) = (namespace, kubeapi.namespace().namespace())
+            && self.is_namespaced()
+            && object != namespace
+        {
+            return Err(ManifestError::NamespaceMismatch {
+                at: self.source.clone(),
+                object: object.to_string(),
+                namespace,
+            });
+        }
+        Ok(namespace)
+    }
+}
+
+/// Same as kubectl, `kind.group/name`, e.g. `deployment.apps/web`
+impl fmt::Display for ManifestObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.kind(), self.object.name_any())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkubectl_kubeapi::Namespace;
+
+    use super::*;
+
+    fn configmap(namespace: Option<&str>) -> ManifestObject {
+        let mut object =
+            api::DynamicObject::new("settings", &Resource::erase::<corev1::ConfigMap>());
+        object.metadata.namespace = namespace.map(ToString::to_string);
+        ManifestObject {
+            object,
+            source: Source {
+                origin: "settings.yaml".to_string(),
+                line: 1,
+            },
+            scope: discovery::Scope::Namespaced,
+            resource: Resource::erase::<corev1::ConfigMap>(),
+        }
+    }
+
+    #[test]
+    fn namespace_mismatch() {
+        let prod = Namespace::Namespace("prod".to_string());
+        let kubeapi = Kubeapi::local().with_namespace(prod);
+
+        assert_eq!(configmap(None).namespace(&kubeapi).unwrap(), None);
+        assert_eq!(
+            configmap(Some("prod")).namespace(&kubeapi).unwrap(),
+            Some("prod")
+        );
+        assert_eq!(
+            configmap(Some("dev")).namespace(&Kubeapi::local()).unwrap(),
+            Some("dev")
+        );
+
+        let err = configmap(Some("dev")).namespace(&kubeapi).unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestError::NamespaceMismatch { object, namespace, .. }
+                if object == "dev" && namespace == "prod"
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+const UNSUPPORTED_MEDIA_TYPE: u16 = 415;
+
+/// Sends `strategic`, a strategic merge patch as made by `three_way_patch()`, falling back to
+/// the JSON merge patch made by `merge` when the server does not take strategic merge patches.
+/// Custom resources only take JSON merge patches, where lists are replaced as a whole.
+pub async fn patch_strategic(
+    api: &api::Api<api::DynamicObject>,
+    name: &str,
+    pp: &api::PatchParams,
+    strategic: json::Value,
+    merge: impl FnOnce() -> json::Value,
+) -> kube::Result<api::DynamicObject> {
+    match api.patch(name, pp, &api::Patch::Strategic(strategic)).await {
+        Err(kube::Error::Api(status)) if status.code == UNSUPPORTED_MEDIA_TYPE => {
+            api.patch(name, pp, &api::Patch::Merge(merge())).await
+        }
+        result => result,
+    }
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+impl Resource {
+    /// Whether the cached discovery lists the `scale` subresource for this resource,
+    /// as it does for workloads and custom resources with `subresources.scale`
+    pub fn has_scale(&self, kubeapi: &Kubeapi) -> bool {
+        self.discovered(kubeapi, Some("scale")).is_some()
+    }
+}
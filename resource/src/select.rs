@@ -0,0 +1,53 @@
+use super::*;
+
+impl ResourceArg {
+    /// The named resources, with the resource types given without a name expanded to their
+    /// objects, along with the namespace of each listed object
+    pub async fn select(
+        resources: Vec<Self>,
+        kubeapi: &Kubeapi,
+        all: bool,
+    ) -> Result<Vec<(NamedResource, Option<String>)>, SelectError> {
+        let mut selected = vec![];
+        for resource in resources {
+            match resource {
+                Self::NamedResource(resource) => selected.push((resource, None)),
+                Self::Resource(resource) => {
+                    let objects = resource.selected(kubeapi, all).await?;
+                    selected.extend(objects.iter().map(|object| {
+                        let named =
+                            NamedResource::with_resource(resource.clone(), object.name_any());
+                        (named, object.namespace())
+                    }));
+                }
+            }
+        }
+        Ok(selected)
+    }
+}
+
+impl Resource {
+    /// The objects of this resource type picked by `--all` or the label selector,
+    /// with their types filled in. Either is required to act on objects without naming them.
+    pub async fn selected(
+        &self,
+        kubeapi: &Kubeapi,
+        all: bool,
+    ) -> Result<Vec<api::DynamicObject>, SelectError> {
+        if !all && !kubeapi.has_label_selector() {
+            return Err(SelectError::NoName);
+        }
+        let objects = ResourceArg::Resource(self.clone())
+            .dynamic_objects(kubeapi)
+            .await?;
+        Ok(objects)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SelectError {
+    #[error("resource(s) were provided, but no name was specified, use --all or a selector")]
+    NoName,
+    #[error(transparent)]
+    Kube(#[from] kube::Error),
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeSet;
+
+use futures_util::stream;
+use futures_util::stream::BoxStream;
+use futures_util::stream::StreamExt;
+use kube::ResourceExt;
+use kube::runtime::watcher;
+use rkubectl_kubeapi::watcher_error;
+use rkubectl_ui::WatchEventType;
+use rkubectl_ui::Watched;
+
+use super::*;
+
+pub type WatchStream = BoxStream<'static, kube::Result<Box<dyn Show>>>;
+
+impl ResourceArg {
+    /// Watch the resource for changes.
+    /// Unless `watch_only` is set, the stream starts with the current objects reported as added.
+    pub async fn watch(
+        &self,
+        kubeapi: &Kubeapi,
+        watch_only: bool,
+        show_event: bool,
+    ) -> kube::Result<WatchStream> {
+        let watch = Watch {
+            name: self.name(),
+            watch_only,
+            show_event,
+        };
+        self.resource().watch(kubeapi, watch).await
+    }
+}
+
+impl Resource {
+    async fn watch(&self, kubeapi: &Kubeapi, watch: Watch<'_>) -> kube::Result<WatchStream> {
+        match self {
+            Self::Pods => watch.start(kubeapi, kubeapi.pods()?).await,
+            Self::Namespaces => watch.start(kubeapi, kubeapi.namespaces()?).await,
+            Self::Nodes => watch.start(kubeapi, kubeapi.nodes()?).await,
+            Self::ConfigMaps => watch.start(kubeapi, kubeapi.configmaps()?).await,
+            Self::ComponentStatuses => watch.start(kubeapi, kubeapi.componentstatuses()?).await,
+            Self::Other { scope, resource } => {
+                let api = kubeapi.dynamic_object_api(scope.clone(), resource)?;
+                watch.start(kubeapi, api).await
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Watch<'a> {
+    name: Option<&'a str>,
+    watch_only: bool,
+    show_event: bool,
+}
+
+impl Watch<'_> {
+    async fn start<K>(self, kubeapi: &Kubeapi, api: api::Api<K>) -> kube::Result<WatchStream>
+    where
+        K: Clone
+            + fmt::Debug
+            + serde::de::DeserializeOwned
+            + kube::Resource
+            + Show
+            + Send
+            + 'static,
+    {
+        let lp = kubeapi.list_params();
+        let field_selector = match self.name {
+            Some(name) => Some(kubeapi.name_field_selector(name)),
+            None => lp.field_selector,
+        };
+        let config = watcher::Config {
+            label_selector: lp.label_selector,
+            field_selector,
+            ..watcher::Config::default()
+        };
+
+        let mut changes = Changes {
+            objects: BTreeMap::new(),
+            listed: BTreeSet::new(),
+            initial: true,
+            watch_only: self.watch_only,
+            show_event: self.show_event,
+        };
+        let stream = watcher::watcher(api, config)
+            .flat_map(move |event| stream::iter(changes.next(event)))
+            .map(|watched| watched.map(|watched| Box::new(watched) as Box<dyn Show>))
+            .boxed();
+        Ok(stream)
+    }
+}
+
+/// Turns watcher events into the changes to show. The first listing reports the current objects
+/// as added, unless only watching. When the watcher lists again, e.g. after the resource version
+/// expired, only the objects that changed or went away meanwhile are reported.
+struct Changes<K> {
+    /// The objects seen so far, by uid
+    objects: BTreeMap<String, K>,
+    /// The objects listed since the watcher (re)started listing
+    listed: BTreeSet<String>,
+    initial: bool,
+    watch_only: bool,
+    show_event: bool,
+}
+
+impl<K> Changes<K>
+where
+    K: Clone + kube::Resource,
+{
+    const GONE: u16 = 410;
+
+    fn next(
+        &mut self,
+        event: Result<watcher::Event<K>, watcher::Error>,
+    ) -> Vec<kube::Result<Watched<K>>> {
+        let (event, object) = match event {
+            Ok(watcher::Event::Init) => {
+                self.listed.clear();
+                return vec![];
+            }
+            Ok(watcher::Event::InitApply(object)) => {
+                self.listed.insert(uid(&object));
+                let event = self.applied(&object);
+                if self.initial && self.watch_only {
+                    return vec![];
+                }
+                match event {
+                    Some(event) => (event, object),
+                    None => return vec![],
+                }
+            }
+            Ok(watcher::Event::InitDone) => {
+                if std::mem::take(&mut self.initial) {
+                    return vec![];
+                }
+                let (listed, gone) = std::mem::take(&mut self.objects)
+                    .into_iter()
+                    .partition(|(uid, _)| self.listed.contains(uid));
+                self.objects = listed;
+                return gone
+                    .into_values()
+                    .map(|object| Ok(self.watched(WatchEventType::Deleted, object)))
+                    .collect();
+            }
+            Ok(watcher::Event::Apply(object)) => match self.applied(&object) {
+                Some(event) => (event, object),
+                None => return vec![],
+            },
+            Ok(watcher::Event::Delete(object)) => {
+                self.objects.remove(&uid(&object));
+                (WatchEventType::Deleted, object)
+            }
+            // The watcher lists again, and the objects are reconciled on `InitDone`
+            Err(watcher::Error::WatchError(status)) if status.code == Self::GONE => {
+                return vec![];
+            }
+            Err(err) => return vec![Err(watcher_error(err))],
+        };
+        vec![Ok(self.watched(event, object))]
+    }
+
+    /// Records `object`, returning how it changed, if it did
+    fn applied(&mut self, object: &K) -> Option<WatchEventType> {
+        let previous = self.objects.insert(uid(object), object.clone());
+        match previous {
+            None => Some(WatchEventType::Added),
+            Some(previous) if previous.resource_version() != object.resource_version() => {
+                Some(WatchEventType::Modified)
+            }
+            Some(_) => None,
+        }
+    }
+
+    fn watched(&self, event: WatchEventType, object: K) -> Watched<K> {
+        Watched {
+            event,
+            object,
+            show_event: self.show_event,
+        }
+    }
+}
+
+fn uid(object: &impl kube::Resource) -> String {
+    object.uid().unwrap_or_else(|| object.name_any())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configmap(uid: &str, version: &str) -> corev1::ConfigMap {
+        corev1::ConfigMap {
+            metadata: metav1::ObjectMeta {
+                name: Some(uid.to_string()),
+                uid: Some(uid.to_string()),
+                resource_version: Some(version.to_string()),
+                ..metav1::ObjectMeta::default()
+            },
+            ..corev1::ConfigMap::default()
+        }
+    }
+
+    fn changes(watch_only: bool) -> Changes<corev1::ConfigMap> {
+        Changes {
+            objects: BTreeMap::new(),
+            listed: BTreeSet::new(),
+            initial: true,
+            watch_only,
+            show_event: true,
+        }
+    }
+
+    fn events(
+        changes: &mut Changes<corev1::ConfigMap>,
+        events: Vec<watcher::Event<corev1::ConfigMap>>,
+    ) -> Vec<(String, String)> {
+        events
+            .into_iter()
+            .flat_map(|event| changes.next(Ok(event)))
+            .map(|watched| {
+                let watched = watched.unwrap();
+                (watched.event.to_string(), watched.object.name_any())
+            })
+            .collect()
+    }
+
+    fn listing(objects: &[(&str, &str)]) -> Vec<watcher::Event<corev1::ConfigMap>> {
+        let objects = objects
+            .iter()
+            .map(|(uid, version)| watcher::Event::InitApply(configmap(uid, version)));
+        std::iter::once(watcher::Event::Init)
+            .chain(objects)
+            .chain(Some(watcher::Event::InitDone))
+            .collect()
+    }
+
+    fn event(event: &str, name: &str) -> (String, String) {
+        (event.to_string(), name.to_string())
+    }
+
+    #[test]
+    fn initial_listing() {
+        let mut shown = changes(false);
+        let listed = events(&mut shown, listing(&[("a", "1"), ("b", "1")]));
+        assert_eq!(listed, [event("ADDED", "a"), event("ADDED", "b")]);
+
+        let mut only = changes(true);
+        assert!(events(&mut only, listing(&[("a", "1")])).is_empty());
+        let modified = events(&mut only, vec![watcher::Event::Apply(configmap("a", "2"))]);
+        assert_eq!(modified, [event("MODIFIED", "a")]);
+    }
+
+    #[test]
+    fn relisting_reports_only_changes() {
+        let mut changes = changes(false);
+        events(&mut changes, listing(&[("a", "1"), ("b", "1"), ("c", "1")]));
+
+        let relisted = events(&mut changes, listing(&[("a", "1"), ("b", "2"), ("d", "1")]));
+        assert_eq!(
+            relisted,
+            [
+                event("MODIFIED", "b"),
+                event("ADDED", "d"),
+                event("DELETED", "c")
+            ]
+        );
+
+        let gone = kube::core::Status::failure("too old", "Expired")
+            .with_code(410)
+            .boxed();
+        assert!(
+            changes
+                .next(Err(watcher::Error::WatchError(gone)))
+                .is_empty()
+        );
+    }
+}
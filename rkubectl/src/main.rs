@@ -10,7 +10,16 @@ async fn main() -> miette::Result<()> {
         .with(EnvFilter::from_default_env())
         .init();
 
-    app::Cli::new().exec().await.into_diagnostic()?;
+    match app::Cli::new().exec().await {
+        // Same as kubectl, `exec` and `attach` exit with the code of the remote process
+        Err(app::RkError::RemoteExit(code)) => {
+            eprintln!("command terminated with exit code {code}");
+            std::process::exit(code);
+        }
+        // The outcome was already reported, e.g. `diff` finding differences
+        Err(app::RkError::Exit(code)) => std::process::exit(code),
+        result => result.into_diagnostic()?,
+    }
 
     Ok(())
 }
@@ -0,0 +1,138 @@
+use k8s::openapi::jiff;
+use serde::de::DeserializeOwned;
+
+use k8s::appsv1;
+
+use super::*;
+
+pub use describer::Describer;
+
+mod describer;
+mod impls;
+
+/// Human readable, multi-section description of an object, as printed by `describe`
+pub trait Describe {
+    fn describe(&self, describer: &mut Describer);
+}
+
+/// Picks the richest `Describe` implementation available for `object`,
+/// falling back to walking the raw object tree for unknown kinds.
+pub fn describable(object: api::DynamicObject) -> Box<dyn Describe> {
+    let types = object.types.clone().unwrap_or_default();
+    match (types.api_version.as_str(), types.kind.as_str()) {
+        ("v1", "Pod") => typed::<corev1::Pod>(object),
+        ("v1", "Node") => typed::<corev1::Node>(object),
+        ("v1", "Namespace") => typed::<corev1::Namespace>(object),
+        ("v1", "Service") => typed::<corev1::Service>(object),
+        ("v1", "Secret") => typed::<corev1::Secret>(object),
+        ("v1", "ConfigMap") => typed::<corev1::ConfigMap>(object),
+        ("apps/v1", "Deployment") => typed::<appsv1::Deployment>(object),
+        _ => Box::new(object),
+    }
+}
+
+/// Renders `object` and, when given, the `Events:` section that follows it
+pub fn describe(object: &dyn Describe, events: Option<&[corev1::Event]>) -> String {
+    let mut describer = Describer::default();
+    object.describe(&mut describer);
+    if let Some(events) = events {
+        describer.events(events);
+    }
+    describer.to_string()
+}
+
+fn typed<K>(object: api::DynamicObject) -> Box<dyn Describe>
+where
+    K: kube::Resource + DeserializeOwned + Describe + 'static,
+{
+    match object.clone().try_parse::<K>() {
+        Ok(k) => Box::new(k),
+        Err(_) => Box::new(object),
+    }
+}
+
+/// Mimics kubectl's RFC1123Z timestamps, e.g. `Mon, 02 Jan 2006 15:04:05 +0000`
+fn timestamp(time: &metav1::Time) -> String {
+    time.0.strftime("%a, %d %b %Y %H:%M:%S %z").to_string()
+}
+
+fn event_age(event: &corev1::Event) -> String {
+    let first = event
+        .first_timestamp
+        .clone()
+        .or_else(|| event.metadata.creation_timestamp.clone());
+    let last = event
+        .last_timestamp
+        .clone()
+        .or_else(|| event.event_time.clone().map(|time| metav1::Time(time.0)))
+        .or_else(|| first.clone());
+    let last = last.map_or_else(|| "<unknown>".to_string(), show::age);
+    match (event.count, first) {
+        (Some(count), Some(first)) if count > 1 => {
+            let first = show::age(first);
+            format!("{last} (x{count} over {first})")
+        }
+        _ => last,
+    }
+}
+
+fn event_source(event: &corev1::Event) -> String {
+    let source = event.source.as_ref();
+    let component = source.and_then(|source| source.component.as_deref());
+    let host = source.and_then(|source| source.host.as_deref());
+    match (component, host) {
+        (Some(component), Some(host)) => format!("{component}, {host}"),
+        (Some(component), None) => component.to_string(),
+        _ => event
+            .reporting_component
+            .clone()
+            .filter(|component| !component.is_empty())
+            .unwrap_or_default(),
+    }
+}
+
+fn event_time(event: &corev1::Event) -> Option<jiff::Timestamp> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|time| time.0)
+        .or_else(|| event.event_time.as_ref().map(|time| time.0))
+        .or_else(|| {
+            event
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|time| time.0)
+        })
+}
+
+impl Describer {
+    /// The `Events:` section, oldest events first
+    pub fn events(&mut self, events: &[corev1::Event]) -> &mut Self {
+        if events.is_empty() {
+            return self.field("Events", "<none>");
+        }
+        let mut events = events.iter().collect::<Vec<_>>();
+        events.sort_by_key(|event| event_time(event));
+        let rows = events
+            .into_iter()
+            .map(|event| {
+                vec![
+                    event.type_.clone().unwrap_or_default(),
+                    event.reason.clone().unwrap_or_default(),
+                    event_age(event),
+                    event_source(event),
+                    event
+                        .message
+                        .as_deref()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                ]
+            })
+            .collect();
+        self.section("Events", |describer| {
+            describer.table(&["Type", "Reason", "Age", "From", "Message"], rows);
+        })
+    }
+}
@@ -0,0 +1,237 @@
+use std::iter;
+
+use super::*;
+
+/// Collects `Key:  value` fields, nested sections and tables, and lays them out
+/// the way `kubectl describe` does: two spaces of indentation per level and values
+/// aligned among the neighbouring fields of the same level.
+#[derive(Debug, Default)]
+pub struct Describer {
+    level: usize,
+    lines: Vec<Line>,
+}
+
+#[derive(Debug)]
+enum Line {
+    Field {
+        level: usize,
+        key: String,
+        value: String,
+    },
+    Text {
+        level: usize,
+        text: String,
+    },
+}
+
+impl Line {
+    fn level(&self) -> usize {
+        match self {
+            Self::Field { level, .. } | Self::Text { level, .. } => *level,
+        }
+    }
+}
+
+impl Describer {
+    /// Single `key:  value` field, multi-line values are aligned under the first line
+    pub fn field(&mut self, key: impl fmt::Display, value: impl fmt::Display) -> &mut Self {
+        let line = Line::Field {
+            level: self.level,
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        self.lines.push(line);
+        self
+    }
+
+    /// Same as `field()`, but shows `<none>` when there is no value
+    pub fn optional(
+        &mut self,
+        key: impl fmt::Display,
+        value: Option<impl fmt::Display>,
+    ) -> &mut Self {
+        match value {
+            Some(value) => self.field(key, value),
+            None => self.field(key, "<none>"),
+        }
+    }
+
+    /// `key=value` pairs one per line, e.g. labels or annotations
+    pub fn map<'a>(
+        &mut self,
+        key: impl fmt::Display,
+        map: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> &mut Self {
+        let value = map
+            .into_iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if value.is_empty() {
+            self.field(key, "<none>")
+        } else {
+            self.field(key, value)
+        }
+    }
+
+    /// `key:` header followed by the fields added by `f`, one level deeper
+    pub fn section(&mut self, key: impl fmt::Display, f: impl FnOnce(&mut Self)) -> &mut Self {
+        self.field(key, "").nested(f)
+    }
+
+    /// Fields added by `f` one level deeper, without a header of their own
+    pub fn nested(&mut self, f: impl FnOnce(&mut Self)) -> &mut Self {
+        self.level += 1;
+        f(self);
+        self.level -= 1;
+        self
+    }
+
+    /// Free form text at the current level
+    pub fn text(&mut self, text: impl fmt::Display) -> &mut Self {
+        let line = Line::Text {
+            level: self.level,
+            text: text.to_string(),
+        };
+        self.lines.push(line);
+        self
+    }
+
+    /// Column aligned table with a dashed line under the header
+    pub fn table(&mut self, header: &[&str], rows: Vec<Vec<String>>) -> &mut Self {
+        let dashes = header
+            .iter()
+            .map(|column| "-".repeat(column.len()))
+            .collect::<Vec<_>>();
+        let header = header.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let rows = iter::once(header)
+            .chain(iter::once(dashes))
+            .chain(rows)
+            .collect::<Vec<_>>();
+        let widths = rows.iter().fold(vec![], |mut widths: Vec<usize>, row| {
+            widths.resize(widths.len().max(row.len()), 0);
+            row.iter()
+                .zip(widths.iter_mut())
+                .for_each(|(cell, width)| *width = (*width).max(cell.chars().count()));
+            widths
+        });
+        for row in rows {
+            let text = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            self.text(text.trim_end());
+        }
+        self
+    }
+
+    /// Width of the `key:` column shared by the field at `index` and its neighbours,
+    /// i.e. all the fields of the same level up to the nearest enclosing section,
+    /// or the nearest text line, e.g. a blank one, as kubectl's tabwriter does.
+    fn key_width(&self, index: usize) -> usize {
+        let level = self.lines[index].level();
+        let boundary = |line: &Line| match line {
+            Line::Field { .. } => line.level() < level,
+            Line::Text { .. } => line.level() <= level,
+        };
+        let start = self.lines[..index]
+            .iter()
+            .rposition(boundary)
+            .map_or(0, |position| position + 1);
+        let end = self.lines[index..]
+            .iter()
+            .position(boundary)
+            .map_or(self.lines.len(), |position| index + position);
+        self.lines[start..end]
+            .iter()
+            .filter_map(|line| match line {
+                Line::Field {
+                    level: l,
+                    key,
+                    value,
+                } if *l == level && !value.is_empty() => Some(key.chars().count() + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+            + 2
+    }
+}
+
+impl fmt::Display for Describer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, line) in self.lines.iter().enumerate() {
+            let indent = "  ".repeat(line.level());
+            match line {
+                Line::Field { key, value, .. } if value.is_empty() => {
+                    writeln!(f, "{indent}{key}:")?;
+                }
+                Line::Field { key, value, .. } => {
+                    let width = self.key_width(index);
+                    let key = format!("{key}:");
+                    let mut values = value.lines();
+                    let first = values.next().unwrap_or_default();
+                    writeln!(f, "{indent}{key:<width$}{first}")?;
+                    for value in values {
+                        writeln!(f, "{indent}{:width$}{value}", "")?;
+                    }
+                }
+                Line::Text { text, .. } if text.is_empty() => writeln!(f)?,
+                Line::Text { text, .. } => {
+                    for text in text.lines() {
+                        writeln!(f, "{indent}{text}")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligned_fields() {
+        let mut describer = Describer::default();
+        describer
+            .field("Name", "nginx")
+            .field("Namespace", "default")
+            .section("Containers", |describer| {
+                describer.section("nginx", |describer| {
+                    describer.field("Image", "nginx:1.27").field("Ready", true);
+                });
+            })
+            .field("QoS Class", "BestEffort");
+        let text = describer.to_string();
+        let expected = "\
+Name:       nginx
+Namespace:  default
+Containers:
+  nginx:
+    Image:  nginx:1.27
+    Ready:  true
+QoS Class:  BestEffort
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn multiline_values() {
+        let labels = BTreeMap::from([
+            ("app".to_string(), "web".to_string()),
+            ("tier".to_string(), "frontend".to_string()),
+        ]);
+        let mut describer = Describer::default();
+        describer.field("Name", "web").map("Labels", &labels);
+        let expected = "\
+Name:    web
+Labels:  app=web
+         tier=frontend
+";
+        assert_eq!(describer.to_string(), expected);
+    }
+}
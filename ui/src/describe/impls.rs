@@ -0,0 +1,379 @@
+use k8s::intstr;
+use k8s::resource::Quantity;
+
+use super::*;
+
+mod configmap;
+mod deployment;
+mod dynamic;
+mod namespace;
+mod node;
+mod pod;
+mod secret;
+mod service;
+
+const LAST_APPLIED_CONFIGURATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// Building blocks shared by the per-kind describers
+impl Describer {
+    fn name(&mut self, meta: &metav1::ObjectMeta) -> &mut Self {
+        self.field("Name", meta.name.as_deref().unwrap_or_default());
+        if let Some(namespace) = meta.namespace.as_deref() {
+            self.field("Namespace", namespace);
+        }
+        self
+    }
+
+    fn labels(&mut self, meta: &metav1::ObjectMeta) -> &mut Self {
+        self.map("Labels", meta.labels.iter().flatten())
+    }
+
+    // Last applied configuration is just noise here, kubectl hides it too
+    fn annotations(&mut self, meta: &metav1::ObjectMeta) -> &mut Self {
+        let annotations = meta
+            .annotations
+            .iter()
+            .flatten()
+            .filter(|(key, _value)| key.as_str() != LAST_APPLIED_CONFIGURATION);
+        self.map("Annotations", annotations)
+    }
+
+    fn creation_timestamp(&mut self, meta: &metav1::ObjectMeta) -> &mut Self {
+        let time = meta.creation_timestamp.as_ref().map(timestamp);
+        self.optional("CreationTimestamp", time)
+    }
+
+    fn quantities(
+        &mut self,
+        key: &str,
+        quantities: Option<&BTreeMap<String, Quantity>>,
+    ) -> &mut Self {
+        match quantities.filter(|quantities| !quantities.is_empty()) {
+            Some(quantities) => self.section(key, |describer| {
+                quantities.iter().for_each(|(name, quantity)| {
+                    describer.field(name, &quantity.0);
+                });
+            }),
+            None => self,
+        }
+    }
+
+    fn conditions(&mut self, header: &[&str], rows: Vec<Vec<String>>) -> &mut Self {
+        if rows.is_empty() {
+            return self.field("Conditions", "<none>");
+        }
+        self.section("Conditions", |describer| {
+            describer.table(header, rows);
+        })
+    }
+
+    fn containers(
+        &mut self,
+        key: &str,
+        containers: &[corev1::Container],
+        statuses: &[corev1::ContainerStatus],
+    ) -> &mut Self {
+        if containers.is_empty() {
+            return self;
+        }
+        self.section(key, |describer| {
+            for container in containers {
+                let status = statuses.iter().find(|status| status.name == container.name);
+                describer.section(&container.name, |describer| {
+                    describer.container(container, status);
+                });
+            }
+        })
+    }
+
+    fn container(
+        &mut self,
+        container: &corev1::Container,
+        status: Option<&corev1::ContainerStatus>,
+    ) -> &mut Self {
+        if let Some(id) = status.and_then(|status| status.container_id.as_deref()) {
+            self.field("Container ID", id);
+        }
+        self.field("Image", container.image.as_deref().unwrap_or_default());
+        if let Some(status) = status {
+            self.field("Image ID", &status.image_id);
+        }
+        let ports = container
+            .ports
+            .iter()
+            .flatten()
+            .map(|port| {
+                let protocol = port.protocol.as_deref().unwrap_or("TCP");
+                format!("{}/{protocol}", port.container_port)
+            })
+            .collect::<Vec<_>>();
+        if ports.is_empty() {
+            self.field("Port", "<none>");
+        } else {
+            self.field(
+                if ports.len() == 1 { "Port" } else { "Ports" },
+                ports.join(", "),
+            );
+        }
+        self.arguments("Command", container.command.as_deref())
+            .arguments("Args", container.args.as_deref());
+        if let Some(status) = status {
+            self.container_state("State", status.state.as_ref());
+            if status.last_state.as_ref().is_some_and(|state| {
+                state.running.is_some() || state.terminated.is_some() || state.waiting.is_some()
+            }) {
+                self.container_state("Last State", status.last_state.as_ref());
+            }
+            self.field("Ready", if status.ready { "True" } else { "False" })
+                .field("Restart Count", status.restart_count);
+        }
+        let resources = container.resources.as_ref();
+        self.quantities("Limits", resources.and_then(|r| r.limits.as_ref()))
+            .quantities("Requests", resources.and_then(|r| r.requests.as_ref()))
+            .environment(container)
+            .mounts(container)
+    }
+
+    fn arguments(&mut self, key: &str, arguments: Option<&[String]>) -> &mut Self {
+        match arguments.filter(|arguments| !arguments.is_empty()) {
+            Some(arguments) => self.section(key, |describer| {
+                arguments.iter().for_each(|argument| {
+                    describer.text(argument);
+                });
+            }),
+            None => self,
+        }
+    }
+
+    fn container_state(&mut self, key: &str, state: Option<&corev1::ContainerState>) -> &mut Self {
+        let state = state.cloned().unwrap_or_default();
+        if let Some(running) = state.running {
+            self.field(key, "Running").nested(|describer| {
+                describer.optional("Started", running.started_at.as_ref().map(timestamp));
+            })
+        } else if let Some(waiting) = state.waiting {
+            self.field(key, "Waiting").nested(|describer| {
+                describer.optional("Reason", waiting.reason.as_deref());
+            })
+        } else if let Some(terminated) = state.terminated {
+            self.field(key, "Terminated").nested(|describer| {
+                describer
+                    .optional("Reason", terminated.reason.as_deref())
+                    .field("Exit Code", terminated.exit_code)
+                    .optional("Started", terminated.started_at.as_ref().map(timestamp))
+                    .optional("Finished", terminated.finished_at.as_ref().map(timestamp));
+            })
+        } else {
+            self.field(key, "Waiting")
+        }
+    }
+
+    fn environment(&mut self, container: &corev1::Container) -> &mut Self {
+        let env = container.env.as_deref().unwrap_or_default();
+        let env_from = container.env_from.as_deref().unwrap_or_default();
+        if env.is_empty() && env_from.is_empty() {
+            return self.field("Environment", "<none>");
+        }
+        if !env_from.is_empty() {
+            self.section("Environment Variables from", |describer| {
+                for source in env_from {
+                    let (kind, name) = if let Some(cm) = source.config_map_ref.as_ref() {
+                        ("ConfigMap", cm.name.as_str())
+                    } else if let Some(secret) = source.secret_ref.as_ref() {
+                        ("Secret", secret.name.as_str())
+                    } else {
+                        continue;
+                    };
+                    describer.field(name, format!("{kind}  Optional: false"));
+                }
+            });
+        }
+        if env.is_empty() {
+            return self;
+        }
+        self.section("Environment", |describer| {
+            for var in env {
+                describer.field(&var.name, env_value(var));
+            }
+        })
+    }
+
+    fn mounts(&mut self, container: &corev1::Container) -> &mut Self {
+        let mounts = container.volume_mounts.as_deref().unwrap_or_default();
+        if mounts.is_empty() {
+            return self.field("Mounts", "<none>");
+        }
+        self.section("Mounts", |describer| {
+            for mount in mounts {
+                let mode = if mount.read_only.unwrap_or_default() {
+                    "ro"
+                } else {
+                    "rw"
+                };
+                describer.text(format!("{} from {} ({mode})", mount.mount_path, mount.name));
+            }
+        })
+    }
+
+    fn volumes(&mut self, volumes: &[corev1::Volume]) -> &mut Self {
+        if volumes.is_empty() {
+            return self.field("Volumes", "<none>");
+        }
+        self.section("Volumes", |describer| {
+            for volume in volumes {
+                describer.section(&volume.name, |describer| {
+                    describer.volume(volume);
+                });
+            }
+        })
+    }
+
+    fn volume(&mut self, volume: &corev1::Volume) -> &mut Self {
+        if let Some(source) = volume.config_map.as_ref() {
+            self.field("Type", "ConfigMap (a volume populated by a ConfigMap)")
+                .field("Name", &source.name)
+        } else if let Some(source) = volume.secret.as_ref() {
+            self.field("Type", "Secret (a volume populated by a Secret)")
+                .field(
+                    "SecretName",
+                    source.secret_name.as_deref().unwrap_or_default(),
+                )
+        } else if let Some(source) = volume.persistent_volume_claim.as_ref() {
+            self.field(
+                "Type",
+                "PersistentVolumeClaim (a reference to a PersistentVolumeClaim in the same namespace)",
+            )
+            .field("ClaimName", &source.claim_name)
+            .field("ReadOnly", source.read_only.unwrap_or_default())
+        } else if let Some(source) = volume.empty_dir.as_ref() {
+            let medium = source.medium.as_deref().unwrap_or_default();
+            self.field(
+                "Type",
+                "EmptyDir (a temporary directory that shares a pod's lifetime)",
+            )
+            .field("Medium", medium)
+        } else if let Some(source) = volume.host_path.as_ref() {
+            self.field("Type", "HostPath (bare host directory volume)")
+                .field("Path", &source.path)
+        } else if volume.projected.is_some() {
+            self.field(
+                "Type",
+                "Projected (a volume that contains injected data from multiple sources)",
+            )
+        } else {
+            self.field("Type", "<unknown>")
+        }
+    }
+
+    fn pod_template(&mut self, template: &corev1::PodTemplateSpec) -> &mut Self {
+        let meta = template.metadata.clone().unwrap_or_default();
+        let spec = template.spec.clone().unwrap_or_default();
+        self.section("Pod Template", |describer| {
+            describer.labels(&meta).annotations(&meta);
+            if let Some(account) = spec.service_account_name.as_deref() {
+                describer.field("Service Account", account);
+            }
+            describer
+                .containers(
+                    "Init Containers",
+                    spec.init_containers.as_deref().unwrap_or_default(),
+                    &[],
+                )
+                .containers("Containers", &spec.containers, &[])
+                .volumes(spec.volumes.as_deref().unwrap_or_default())
+                .map("Node-Selectors", spec.node_selector.iter().flatten())
+                .tolerations(spec.tolerations.as_deref().unwrap_or_default());
+        })
+    }
+
+    fn tolerations(&mut self, tolerations: &[corev1::Toleration]) -> &mut Self {
+        let tolerations = tolerations
+            .iter()
+            .map(toleration)
+            .collect::<Vec<_>>()
+            .join("\n");
+        if tolerations.is_empty() {
+            self.field("Tolerations", "<none>")
+        } else {
+            self.field("Tolerations", tolerations)
+        }
+    }
+}
+
+fn env_value(var: &corev1::EnvVar) -> String {
+    if let Some(value) = var.value.as_deref() {
+        return value.to_string();
+    }
+    let Some(source) = var.value_from.as_ref() else {
+        return String::new();
+    };
+    if let Some(field) = source.field_ref.as_ref() {
+        let version = field.api_version.as_deref().unwrap_or("v1");
+        format!("({version}:{})", field.field_path)
+    } else if let Some(resource) = source.resource_field_ref.as_ref() {
+        format!(
+            "{} of {}",
+            resource.resource,
+            resource.container_name.as_deref().unwrap_or_default()
+        )
+    } else if let Some(key) = source.config_map_key_ref.as_ref() {
+        format!(
+            "<set to the key '{}' of config map '{}'>",
+            key.key, key.name
+        )
+    } else if let Some(key) = source.secret_key_ref.as_ref() {
+        format!("<set to the key '{}' in secret '{}'>", key.key, key.name)
+    } else {
+        String::new()
+    }
+}
+
+fn toleration(toleration: &corev1::Toleration) -> String {
+    let key = toleration.key.as_deref().unwrap_or_default();
+    let mut text = match toleration.value.as_deref() {
+        Some(value) if !value.is_empty() => format!("{key}={value}"),
+        _ => key.to_string(),
+    };
+    if let Some(effect) = toleration.effect.as_deref() {
+        text = format!("{text}:{effect}");
+    }
+    if toleration.operator.as_deref() == Some("Exists") && key.is_empty() {
+        text = format!("op=Exists{text}");
+    } else if toleration.operator.as_deref() == Some("Exists") {
+        text = format!("{text} op=Exists");
+    }
+    if let Some(seconds) = toleration.toleration_seconds {
+        text = format!("{text} for {seconds}s");
+    }
+    text
+}
+
+fn label_selector(selector: &metav1::LabelSelector) -> String {
+    let labels = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(key, value)| format!("{key}={value}"));
+    let expressions = selector
+        .match_expressions
+        .iter()
+        .flatten()
+        .map(|expression| {
+            let values = expression.values.as_deref().unwrap_or_default().join(",");
+            match expression.operator.as_str() {
+                "In" => format!("{} in ({values})", expression.key),
+                "NotIn" => format!("{} notin ({values})", expression.key),
+                "Exists" => expression.key.clone(),
+                "DoesNotExist" => format!("!{}", expression.key),
+                other => format!("{} {other} ({values})", expression.key),
+            }
+        });
+    labels.chain(expressions).collect::<Vec<_>>().join(",")
+}
+
+fn int_or_string(value: &intstr::IntOrString) -> String {
+    match value {
+        intstr::IntOrString::Int(value) => value.to_string(),
+        intstr::IntOrString::String(value) => value.clone(),
+    }
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+impl Describe for corev1::ConfigMap {
+    fn describe(&self, describer: &mut Describer) {
+        describer
+            .name(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .text("")
+            .text("Data")
+            .text("====");
+        for (key, value) in self.data.iter().flatten() {
+            describer.field(key, "").text("----").text(value).text("");
+        }
+        describer.text("").text("BinaryData").text("====");
+        for (key, value) in self.binary_data.iter().flatten() {
+            describer.field(key, format!("{} bytes", value.0.len()));
+        }
+        describer.text("");
+    }
+}
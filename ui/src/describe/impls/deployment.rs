@@ -0,0 +1,126 @@
+use super::*;
+
+impl Describe for appsv1::Deployment {
+    fn describe(&self, describer: &mut Describer) {
+        let spec = self.spec.clone().unwrap_or_default();
+        let status = self.status.clone().unwrap_or_default();
+
+        let desired = spec.replicas.unwrap_or(1);
+        let updated = status.updated_replicas.unwrap_or_default();
+        let total = status.replicas.unwrap_or_default();
+        let available = status.available_replicas.unwrap_or_default();
+        let unavailable = status.unavailable_replicas.unwrap_or_default();
+        let replicas = format!(
+            "{desired} desired | {updated} updated | {total} total | {available} available | {unavailable} unavailable"
+        );
+        let strategy = spec.strategy.clone().unwrap_or_default();
+
+        describer
+            .name(&self.metadata)
+            .creation_timestamp(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .field("Selector", label_selector(&spec.selector))
+            .field("Replicas", replicas)
+            .field(
+                "StrategyType",
+                strategy.type_.as_deref().unwrap_or("RollingUpdate"),
+            )
+            .field(
+                "MinReadySeconds",
+                spec.min_ready_seconds.unwrap_or_default(),
+            );
+        if let Some(rolling) = strategy.rolling_update.as_ref() {
+            let unavailable = rolling
+                .max_unavailable
+                .as_ref()
+                .map_or_else(|| "25%".to_string(), int_or_string);
+            let surge = rolling
+                .max_surge
+                .as_ref()
+                .map_or_else(|| "25%".to_string(), int_or_string);
+            describer.field(
+                "RollingUpdateStrategy",
+                format!("{unavailable} max unavailable, {surge} max surge"),
+            );
+        }
+
+        let conditions = status
+            .conditions
+            .iter()
+            .flatten()
+            .map(|condition| {
+                vec![
+                    condition.type_.clone(),
+                    condition.status.clone(),
+                    condition.reason.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        describer
+            .pod_template(&spec.template)
+            .conditions(&["Type", "Status", "Reason"], conditions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolling_deployment() {
+        let deployment = json::from_value::<appsv1::Deployment>(json::json!({
+            "metadata": {"name": "web", "namespace": "default", "labels": {"app": "web"}},
+            "spec": {
+                "replicas": 3,
+                "selector": {"matchLabels": {"app": "web"}},
+                "strategy": {"type": "RollingUpdate", "rollingUpdate": {"maxUnavailable": 1, "maxSurge": "50%"}},
+                "template": {
+                    "metadata": {"labels": {"app": "web"}},
+                    "spec": {"containers": [{"name": "nginx", "image": "nginx:1.27"}]}
+                }
+            },
+            "status": {
+                "replicas": 3,
+                "updatedReplicas": 3,
+                "availableReplicas": 2,
+                "unavailableReplicas": 1,
+                "conditions": [
+                    {"type": "Available", "status": "False", "reason": "MinimumReplicasUnavailable"},
+                    {"type": "Progressing", "status": "True", "reason": "NewReplicaSetAvailable"}
+                ]
+            }
+        }))
+        .unwrap();
+        let expected = "\
+Name:                   web
+Namespace:              default
+CreationTimestamp:      <none>
+Labels:                 app=web
+Annotations:            <none>
+Selector:               app=web
+Replicas:               3 desired | 3 updated | 3 total | 2 available | 1 unavailable
+StrategyType:           RollingUpdate
+MinReadySeconds:        0
+RollingUpdateStrategy:  1 max unavailable, 50% max surge
+Pod Template:
+  Labels:          app=web
+  Annotations:     <none>
+  Containers:
+    nginx:
+      Image:        nginx:1.27
+      Port:         <none>
+      Environment:  <none>
+      Mounts:       <none>
+  Volumes:         <none>
+  Node-Selectors:  <none>
+  Tolerations:     <none>
+Conditions:
+  Type         Status  Reason
+  ----         ------  ------
+  Available    False   MinimumReplicasUnavailable
+  Progressing  True    NewReplicaSetAvailable
+";
+        assert_eq!(describe(&deployment, None), expected);
+    }
+}
@@ -0,0 +1,74 @@
+use heck::ToTitleCase;
+
+use super::*;
+
+// Metadata fields already shown at the top, or too noisy to be useful
+const SKIPPED_METADATA: &[&str] = &[
+    "name",
+    "namespace",
+    "labels",
+    "annotations",
+    "managedFields",
+];
+
+/// Generic describer for kinds without a dedicated implementation,
+/// walks the object tree much like kubectl does for custom resources.
+impl Describe for api::DynamicObject {
+    fn describe(&self, describer: &mut Describer) {
+        describer
+            .name(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata);
+        if let Some(types) = self.types.as_ref() {
+            describer
+                .field("API Version", &types.api_version)
+                .field("Kind", &types.kind);
+        }
+        if let Ok(json::Value::Object(mut metadata)) = json::to_value(&self.metadata) {
+            metadata.retain(|key, _value| !SKIPPED_METADATA.contains(&key.as_str()));
+            walk(describer, "metadata", &json::Value::Object(metadata));
+        }
+        if let json::Value::Object(data) = &self.data {
+            data.iter()
+                .for_each(|(key, value)| walk(describer, key, value));
+        }
+    }
+}
+
+fn walk(describer: &mut Describer, key: &str, value: &json::Value) {
+    let key = key.to_title_case();
+    match value {
+        json::Value::Null => {}
+        json::Value::Object(object) if object.is_empty() => {}
+        json::Value::Array(array) if array.is_empty() => {}
+        json::Value::Object(object) => {
+            describer.section(key, |describer| {
+                object
+                    .iter()
+                    .for_each(|(key, value)| walk(describer, key, value));
+            });
+        }
+        json::Value::Array(array) => {
+            describer.section(key, |describer| {
+                array.iter().for_each(|item| match item {
+                    json::Value::Object(object) => object
+                        .iter()
+                        .for_each(|(key, value)| walk(describer, key, value)),
+                    other => {
+                        describer.text(scalar(other));
+                    }
+                });
+            });
+        }
+        other => {
+            describer.field(key, scalar(other));
+        }
+    }
+}
+
+fn scalar(value: &json::Value) -> String {
+    match value {
+        json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+impl Describe for corev1::Namespace {
+    fn describe(&self, describer: &mut Describer) {
+        describer
+            .name(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .field("Status", self.get_status());
+    }
+}
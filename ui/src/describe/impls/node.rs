@@ -0,0 +1,111 @@
+use super::*;
+
+const NODE_ROLE_PREFIX: &str = "node-role.kubernetes.io/";
+
+impl Describe for corev1::Node {
+    fn describe(&self, describer: &mut Describer) {
+        let spec = self.spec.clone().unwrap_or_default();
+        let status = self.status.clone().unwrap_or_default();
+
+        let roles = self
+            .labels()
+            .keys()
+            .filter_map(|key| key.strip_prefix(NODE_ROLE_PREFIX))
+            .collect::<Vec<_>>()
+            .join(",");
+        let roles = if roles.is_empty() {
+            "<none>".to_string()
+        } else {
+            roles
+        };
+        let taints = spec
+            .taints
+            .iter()
+            .flatten()
+            .map(|taint| match taint.value.as_deref() {
+                Some(value) => format!("{}={value}:{}", taint.key, taint.effect),
+                None => format!("{}:{}", taint.key, taint.effect),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let taints = if taints.is_empty() {
+            "<none>".to_string()
+        } else {
+            taints
+        };
+
+        describer
+            .name(&self.metadata)
+            .field("Roles", roles)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .creation_timestamp(&self.metadata)
+            .field("Taints", taints)
+            .field("Unschedulable", spec.unschedulable.unwrap_or_default());
+
+        let conditions = status
+            .conditions
+            .iter()
+            .flatten()
+            .map(|condition| {
+                vec![
+                    condition.type_.clone(),
+                    condition.status.clone(),
+                    condition
+                        .last_heartbeat_time
+                        .as_ref()
+                        .map(timestamp)
+                        .unwrap_or_default(),
+                    condition
+                        .last_transition_time
+                        .as_ref()
+                        .map(timestamp)
+                        .unwrap_or_default(),
+                    condition.reason.clone().unwrap_or_default(),
+                    condition.message.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        describer.conditions(
+            &[
+                "Type",
+                "Status",
+                "LastHeartbeatTime",
+                "LastTransitionTime",
+                "Reason",
+                "Message",
+            ],
+            conditions,
+        );
+
+        let addresses = status.addresses.unwrap_or_default();
+        describer.section("Addresses", |describer| {
+            addresses.iter().for_each(|address| {
+                describer.field(&address.type_, &address.address);
+            });
+        });
+        describer
+            .quantities("Capacity", status.capacity.as_ref())
+            .quantities("Allocatable", status.allocatable.as_ref());
+        if let Some(info) = status.node_info.as_ref() {
+            describer.section("System Info", |describer| {
+                describer
+                    .field("Machine ID", &info.machine_id)
+                    .field("System UUID", &info.system_uuid)
+                    .field("Boot ID", &info.boot_id)
+                    .field("Kernel Version", &info.kernel_version)
+                    .field("OS Image", &info.os_image)
+                    .field("Operating System", &info.operating_system)
+                    .field("Architecture", &info.architecture)
+                    .field("Container Runtime Version", &info.container_runtime_version)
+                    .field("Kubelet Version", &info.kubelet_version)
+                    .field("Kube-Proxy Version", &info.kube_proxy_version);
+            });
+        }
+        describer.field("PodCIDR", spec.pod_cidr.as_deref().unwrap_or_default());
+        describer.field("PodCIDRs", spec.pod_cidrs.unwrap_or_default().join(","));
+        if let Some(provider) = spec.provider_id.as_deref() {
+            describer.field("ProviderID", provider);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+impl Describe for corev1::Pod {
+    fn describe(&self, describer: &mut Describer) {
+        let spec = self.spec.clone().unwrap_or_default();
+        let status = self.status.clone().unwrap_or_default();
+
+        describer.name(&self.metadata);
+        describer.field("Priority", spec.priority.unwrap_or_default());
+        if let Some(class) = spec.priority_class_name.as_deref() {
+            describer.field("Priority Class Name", class);
+        }
+        describer.field(
+            "Service Account",
+            spec.service_account_name.as_deref().unwrap_or_default(),
+        );
+        let node = spec
+            .node_name
+            .as_deref()
+            .map(|node| match status.host_ip.as_deref() {
+                Some(ip) => format!("{node}/{ip}"),
+                None => node.to_string(),
+            });
+        describer
+            .optional("Node", node)
+            .optional("Start Time", status.start_time.as_ref().map(timestamp))
+            .labels(&self.metadata)
+            .annotations(&self.metadata);
+
+        let phase = status.phase.as_deref().unwrap_or_default();
+        match self.metadata.deletion_timestamp.as_ref() {
+            Some(deleted) => {
+                describer.field(
+                    "Status",
+                    format!("Terminating (lasts {})", show::age(deleted.clone())),
+                );
+                if let Some(grace) = self.metadata.deletion_grace_period_seconds {
+                    describer.field("Termination Grace Period", format!("{grace}s"));
+                }
+            }
+            None => {
+                describer.field("Status", phase);
+            }
+        }
+        if let Some(reason) = status.reason.as_deref() {
+            describer.field("Reason", reason);
+        }
+        if let Some(message) = status.message.as_deref() {
+            describer.field("Message", message);
+        }
+        describer.field("IP", status.pod_ip.as_deref().unwrap_or_default());
+        let ips = status.pod_ips.unwrap_or_default();
+        if ips.is_empty() {
+            describer.field("IPs", "<none>");
+        } else {
+            describer.section("IPs", |describer| {
+                ips.iter().for_each(|ip| {
+                    describer.field("IP", &ip.ip);
+                });
+            });
+        }
+        if let Some(owner) = self
+            .owner_references()
+            .iter()
+            .find(|owner| owner.controller == Some(true))
+        {
+            describer.field("Controlled By", format!("{}/{}", owner.kind, owner.name));
+        }
+
+        let conditions = status
+            .conditions
+            .iter()
+            .flatten()
+            .map(|condition| vec![condition.type_.clone(), condition.status.clone()])
+            .collect();
+        describer
+            .containers(
+                "Init Containers",
+                spec.init_containers.as_deref().unwrap_or_default(),
+                status
+                    .init_container_statuses
+                    .as_deref()
+                    .unwrap_or_default(),
+            )
+            .containers(
+                "Containers",
+                &spec.containers,
+                status.container_statuses.as_deref().unwrap_or_default(),
+            )
+            .conditions(&["Type", "Status"], conditions)
+            .volumes(spec.volumes.as_deref().unwrap_or_default())
+            .field("QoS Class", status.qos_class.as_deref().unwrap_or_default())
+            .map("Node-Selectors", spec.node_selector.iter().flatten())
+            .tolerations(spec.tolerations.as_deref().unwrap_or_default());
+    }
+}
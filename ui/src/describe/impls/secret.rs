@@ -0,0 +1,61 @@
+use super::*;
+
+// Only the keys and the size of the values, the data itself is never shown
+impl Describe for corev1::Secret {
+    fn describe(&self, describer: &mut Describer) {
+        describer
+            .name(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .text("")
+            .field("Type", self.type_.as_deref().unwrap_or("Opaque"))
+            .text("")
+            .text("Data")
+            .text("====");
+        let data = self
+            .data
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key, value.0.len()));
+        let string_data = self
+            .string_data
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key, value.len()));
+        for (key, size) in data.chain(string_data) {
+            describer.field(key, format!("{size} bytes"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_only() {
+        let secret = json::from_value::<corev1::Secret>(json::json!({
+            "metadata": {"name": "credentials", "namespace": "default"},
+            "type": "Opaque",
+            "data": {"password": "aHVudGVyMg=="},
+            "stringData": {"token": "s3cr3t"}
+        }))
+        .unwrap();
+        let text = describe(&secret, None);
+        let expected = "\
+Name:         credentials
+Namespace:    default
+Labels:       <none>
+Annotations:  <none>
+
+Type:  Opaque
+
+Data
+====
+password:  7 bytes
+token:     6 bytes
+";
+        assert_eq!(text, expected);
+        assert!(!text.contains("hunter2") && !text.contains("s3cr3t"));
+    }
+}
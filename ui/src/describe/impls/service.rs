@@ -0,0 +1,77 @@
+use super::*;
+
+impl Describe for corev1::Service {
+    fn describe(&self, describer: &mut Describer) {
+        let spec = self.spec.clone().unwrap_or_default();
+        let status = self.status.clone().unwrap_or_default();
+
+        let selector = spec
+            .selector
+            .iter()
+            .flatten()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let selector = if selector.is_empty() {
+            "<none>".to_string()
+        } else {
+            selector
+        };
+
+        describer
+            .name(&self.metadata)
+            .labels(&self.metadata)
+            .annotations(&self.metadata)
+            .field("Selector", selector)
+            .field("Type", spec.type_.as_deref().unwrap_or("ClusterIP"));
+        if let Some(policy) = spec.ip_family_policy.as_deref() {
+            describer.field("IP Family Policy", policy);
+        }
+        if let Some(families) = spec.ip_families.as_ref() {
+            describer.field("IP Families", families.join(","));
+        }
+        if let Some(ip) = spec.cluster_ip.as_deref() {
+            describer.field("IP", ip);
+        }
+        if let Some(ips) = spec.cluster_ips.as_ref() {
+            describer.field("IPs", ips.join(","));
+        }
+        if let Some(name) = spec.external_name.as_deref() {
+            describer.field("External Name", name);
+        }
+        let ingress = status
+            .load_balancer
+            .and_then(|lb| lb.ingress)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|ingress| ingress.ip.or(ingress.hostname))
+            .collect::<Vec<_>>();
+        if !ingress.is_empty() {
+            describer.field("LoadBalancer Ingress", ingress.join(", "));
+        }
+        for port in spec.ports.iter().flatten() {
+            let name = port.name.as_deref().unwrap_or("<unset>");
+            let protocol = port.protocol.as_deref().unwrap_or("TCP");
+            let target = port
+                .target_port
+                .as_ref()
+                .map_or_else(|| port.port.to_string(), int_or_string);
+            describer
+                .field("Port", format!("{name}  {}/{protocol}", port.port))
+                .field("TargetPort", format!("{target}/{protocol}"));
+            if let Some(node_port) = port.node_port {
+                describer.field("NodePort", format!("{name}  {node_port}/{protocol}"));
+            }
+        }
+        describer.field(
+            "Session Affinity",
+            spec.session_affinity.as_deref().unwrap_or("None"),
+        );
+        if let Some(policy) = spec.external_traffic_policy.as_deref() {
+            describer.field("External Traffic Policy", policy);
+        }
+        if let Some(policy) = spec.internal_traffic_policy.as_deref() {
+            describer.field("Internal Traffic Policy", policy);
+        }
+    }
+}
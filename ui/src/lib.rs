@@ -8,6 +8,7 @@ use kube::api;
 use serde_json as json;
 use serde_yaml as yaml;
 
+use rkubectl_ext::EventExt;
 use rkubectl_ext::NamespaceGetExt2;
 use rkubectl_ext::PodGetExt2;
 
@@ -17,13 +18,23 @@ use k8s::ComponentStatusGetExt;
 use k8s::authenticationv1;
 use k8s::authorizationv1;
 use k8s::corev1;
+use k8s::eventsv1;
 use k8s::metav1;
 
+pub use describe::Describe;
+pub use describe::Describer;
+pub use describe::describable;
+pub use describe::describe;
 pub use show::Show;
 pub use show::ShowParams;
+pub use watch::RowPrinter;
+pub use watch::WatchEventType;
+pub use watch::Watched;
 
 mod convert;
+mod describe;
 mod show;
+mod watch;
 
 #[derive(Debug)]
 pub struct Ui {
@@ -158,7 +158,10 @@ where
     }
 
     fn name(&self) -> String {
-        todo!()
+        self.iter()
+            .map(|item| item.name())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
@@ -176,7 +179,7 @@ where
     }
 }
 
-fn age(time: metav1::Time) -> String {
+pub(crate) fn age(time: metav1::Time) -> String {
     let delta = jiff::Timestamp::now() - time.0;
     human_time(delta)
 }
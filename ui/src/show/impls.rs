@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::iter;
 
 use super::*;
 
@@ -6,6 +7,8 @@ mod accessreview;
 mod componentstatus;
 mod configmap;
 mod created;
+mod dynamic;
+mod event;
 mod feature;
 mod namespace;
 mod node;
@@ -13,6 +16,7 @@ mod objectlist;
 mod pod;
 mod service;
 mod subjectreview;
+mod table;
 
 impl Show for (String, String) {
     fn header(&self, _output: OutputFormat) -> Vec<String> {
@@ -0,0 +1,53 @@
+use super::*;
+
+impl Show for api::DynamicObject {
+    fn header(&self, output: OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Normal | OutputFormat::Wide => ["NAMESPACE", "NAME", "AGE"].as_slice(),
+            _ => todo!("{output:?}"),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: ShowParams, output: OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = if params.show_kind {
+            self.name()
+        } else {
+            self.name_any()
+        };
+        let age = self.creation_timestamp().map(age).unwrap_or_default();
+        match output {
+            OutputFormat::Normal | OutputFormat::Wide => vec![namespace, name, age],
+            _ => todo!("{output:?}"),
+        }
+    }
+
+    fn yaml(&self, params: ShowParams) -> String {
+        let data = self.maybe_strip_managed_fields(params);
+        yaml::to_string(&data).unwrap_or_default()
+    }
+
+    fn json(&self, params: ShowParams) -> String {
+        let data = self.maybe_strip_managed_fields(params);
+        json::to_string_pretty(&data).unwrap_or_default()
+    }
+
+    // Mimics `kubectl get -o name`, i.e. `kind.group/name`
+    fn name(&self) -> String {
+        let name = self.name_any();
+        let Some(types) = self.types.as_ref() else {
+            return name;
+        };
+        format!("{}/{name}", qualified_kind(types))
+    }
+}
+
+/// `kind.group`, or just `kind` for the core group
+pub(super) fn qualified_kind(types: &api::TypeMeta) -> String {
+    let kind = types.kind.to_lowercase();
+    match types.api_version.split_once('/') {
+        Some((group, _version)) => format!("{kind}.{group}"),
+        None => kind,
+    }
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+impl Show for eventsv1::Event {
+    fn header(&self, output: OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Normal => [
+                "NAMESPACE",
+                "LAST SEEN",
+                "TYPE",
+                "REASON",
+                "OBJECT",
+                "MESSAGE",
+            ]
+            .as_slice(),
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "LAST SEEN",
+                "TYPE",
+                "REASON",
+                "OBJECT",
+                "SUBOBJECT",
+                "SOURCE",
+                "MESSAGE",
+                "FIRST SEEN",
+                "COUNT",
+                "NAME",
+            ]
+            .as_slice(),
+            _ => todo!("{output:?}"),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, _params: ShowParams, output: OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let count = self.count();
+        let first = self.first_seen().map(seen);
+        let last = self.last_seen().map(seen);
+        let last_seen = match (last.clone(), first.clone()) {
+            (Some(last), Some(first)) if count > 1 => format!("{last} (x{count} over {first})"),
+            (last, _) => last.unwrap_or_else(|| "<unknown>".to_string()),
+        };
+        let type_ = self.type_.clone().unwrap_or_default();
+        let reason = self.reason.clone().unwrap_or_default();
+        let regarding = self.regarding.as_ref();
+        let object = regarding
+            .map(|object| {
+                let kind = object.kind.as_deref().unwrap_or_default().to_lowercase();
+                let name = object.name.as_deref().unwrap_or_default();
+                format!("{kind}/{name}")
+            })
+            .unwrap_or_default();
+        let message = self.note.clone().unwrap_or_default().trim().to_string();
+        match output {
+            OutputFormat::Normal => vec![namespace, last_seen, type_, reason, object, message],
+            OutputFormat::Wide => {
+                let subobject = regarding
+                    .and_then(|object| object.field_path.clone())
+                    .unwrap_or_default();
+                let source = source(self);
+                let first_seen = first.unwrap_or_else(|| "<unknown>".to_string());
+                vec![
+                    namespace,
+                    last_seen,
+                    type_,
+                    reason,
+                    object,
+                    subobject,
+                    source,
+                    message,
+                    first_seen,
+                    count.to_string(),
+                    self.name_any(),
+                ]
+            }
+            _ => todo!("{output:?}"),
+        }
+    }
+
+    fn yaml(&self, params: ShowParams) -> String {
+        let data = self.maybe_strip_managed_fields(params);
+        yaml::to_string(&data).unwrap_or_default()
+    }
+
+    fn json(&self, params: ShowParams) -> String {
+        let data = self.maybe_strip_managed_fields(params);
+        json::to_string_pretty(&data).unwrap_or_default()
+    }
+
+    fn name(&self) -> String {
+        format!("event.events.k8s.io/{}", self.name_any())
+    }
+}
+
+fn source(event: &eventsv1::Event) -> String {
+    let source = event.deprecated_source.as_ref();
+    let component = source.and_then(|source| source.component.as_deref());
+    let host = source.and_then(|source| source.host.as_deref());
+    match (component, host) {
+        (Some(component), Some(host)) => format!("{component}, {host}"),
+        (Some(component), None) => component.to_string(),
+        _ => event.reporting_controller.clone().unwrap_or_default(),
+    }
+}
+
+fn seen(time: jiff::Timestamp) -> String {
+    age(metav1::Time(time))
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::ToSpan;
+
+    use super::*;
+
+    fn event(first: Option<jiff::Span>, last: Option<jiff::Span>, count: i32) -> eventsv1::Event {
+        let now = jiff::Timestamp::now();
+        let ago = |span: jiff::Span| metav1::Time(now - span);
+        eventsv1::Event {
+            metadata: metav1::ObjectMeta {
+                name: Some("web.1".to_string()),
+                namespace: Some("default".to_string()),
+                ..metav1::ObjectMeta::default()
+            },
+            deprecated_first_timestamp: first.map(ago),
+            deprecated_last_timestamp: last.map(ago),
+            deprecated_count: Some(count),
+            type_: Some("Normal".to_string()),
+            reason: Some("Pulled".to_string()),
+            regarding: Some(corev1::ObjectReference {
+                kind: Some("Pod".to_string()),
+                name: Some("web".to_string()),
+                ..corev1::ObjectReference::default()
+            }),
+            note: Some("Container image pulled\n".to_string()),
+            ..eventsv1::Event::default()
+        }
+    }
+
+    fn last_seen(event: &eventsv1::Event) -> String {
+        let header = event.header(OutputFormat::Normal);
+        let data = event.data(ShowParams::default(), OutputFormat::Normal);
+        let column = header.iter().position(|name| name == "LAST SEEN").unwrap();
+        data[column].clone()
+    }
+
+    #[test]
+    fn normal() {
+        let event = event(Some(30.minutes()), Some(30.minutes()), 1);
+        assert_eq!(
+            event.data(ShowParams::default(), OutputFormat::Normal),
+            [
+                "default",
+                "30m",
+                "Normal",
+                "Pulled",
+                "pod/web",
+                "Container image pulled"
+            ]
+        );
+    }
+
+    #[test]
+    fn last_seen_repeated() {
+        let event = event(Some(120.minutes()), Some(30.minutes()), 3);
+        assert_eq!(last_seen(&event), "30m (x3 over 120m)");
+    }
+
+    #[test]
+    fn last_seen_unknown() {
+        let event = event(None, None, 1);
+        assert_eq!(last_seen(&event), "<unknown>");
+    }
+}
@@ -45,6 +45,6 @@ where
     }
 
     fn name(&self) -> String {
-        todo!()
+        self.items.name()
     }
 }
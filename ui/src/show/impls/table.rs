@@ -0,0 +1,197 @@
+use rkubectl_ext::TableColumnDefinition;
+use rkubectl_ext::TableRow;
+
+use super::dynamic::qualified_kind;
+use super::*;
+
+impl Show for rkubectl_ext::Table {
+    fn header(&self, output: OutputFormat) -> Vec<String> {
+        let namespace = self.has_namespace().then(|| "NAMESPACE".to_string());
+        let columns = self
+            .columns(output)
+            .map(|(_, column)| column.name.to_uppercase());
+        namespace.into_iter().chain(columns).collect()
+    }
+
+    fn data(&self, params: ShowParams, output: OutputFormat) -> Vec<String> {
+        let has_namespace = self.has_namespace();
+        self.rows
+            .first()
+            .map(|row| self.row(row, has_namespace, params, output))
+            .unwrap_or_default()
+    }
+
+    fn normal(&self, params: ShowParams, output: OutputFormat) -> Table {
+        let header = self.header(output);
+        let has_namespace = self.has_namespace();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| self.row(row, has_namespace, params, output));
+        tabled::builder::Builder::from_iter(iter::once(header).chain(rows)).build()
+    }
+
+    fn wide(&self, params: ShowParams, output: OutputFormat) -> Table {
+        self.normal(params, output)
+    }
+
+    fn yaml(&self, _params: ShowParams) -> String {
+        yaml::to_string(self).unwrap_or_default()
+    }
+
+    fn json(&self, _params: ShowParams) -> String {
+        json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    fn name(&self) -> String {
+        self.rows
+            .iter()
+            .filter_map(|row| row.object.as_ref())
+            .map(|object| object.name())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+trait TableExt {
+    fn has_namespace(&self) -> bool;
+    fn columns(
+        &self,
+        output: OutputFormat,
+    ) -> impl Iterator<Item = (usize, &TableColumnDefinition)>;
+    fn row(
+        &self,
+        row: &TableRow,
+        has_namespace: bool,
+        params: ShowParams,
+        output: OutputFormat,
+    ) -> Vec<String>;
+}
+
+impl TableExt for rkubectl_ext::Table {
+    fn has_namespace(&self) -> bool {
+        self.rows
+            .iter()
+            .filter_map(|row| row.object.as_ref())
+            .any(|object| object.metadata.namespace.is_some())
+    }
+
+    // Columns with non-zero priority are only shown in wide output
+    fn columns(
+        &self,
+        output: OutputFormat,
+    ) -> impl Iterator<Item = (usize, &TableColumnDefinition)> {
+        self.column_definitions
+            .iter()
+            .enumerate()
+            .filter(move |(_, column)| output.is_wide() || column.priority == 0)
+    }
+
+    // `has_namespace` is the same for all the rows, worked out once by the caller
+    fn row(
+        &self,
+        row: &TableRow,
+        has_namespace: bool,
+        params: ShowParams,
+        output: OutputFormat,
+    ) -> Vec<String> {
+        let object = row.object.as_ref();
+        let namespace = has_namespace.then(|| {
+            object
+                .and_then(|object| object.namespace())
+                .unwrap_or_default()
+        });
+        let cells = self.columns(output).map(|(idx, column)| {
+            let value = row.cells.get(idx).unwrap_or(&json::Value::Null);
+            let cell = cell(column, value);
+            match object.and_then(|object| object.types.as_ref()) {
+                Some(types) if params.show_kind && column.name == "Name" => {
+                    format!("{}/{cell}", qualified_kind(types))
+                }
+                _ => cell,
+            }
+        });
+        namespace.into_iter().chain(cells).collect()
+    }
+}
+
+fn cell(column: &TableColumnDefinition, value: &json::Value) -> String {
+    match value {
+        json::Value::Null => "<none>".to_string(),
+        json::Value::String(text) if column.is_date() => {
+            json::from_value::<metav1::Time>(value.clone()).map_or_else(|_| text.clone(), age)
+        }
+        json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> rkubectl_ext::Table {
+        json::from_value(json::json!({
+            "kind": "Table",
+            "apiVersion": "meta.k8s.io/v1",
+            "metadata": {"resourceVersion": "42"},
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "priority": 0},
+                {"name": "Ready", "type": "string", "priority": 0},
+                {"name": "Node", "type": "string", "priority": 1},
+            ],
+            "rows": [{
+                "cells": ["nginx", "1/1", null],
+                "object": {"metadata": {"name": "nginx", "namespace": "default"}},
+            }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn normal_skips_priority_columns() {
+        let table = table();
+        assert_eq!(
+            table.header(OutputFormat::Normal),
+            ["NAMESPACE", "NAME", "READY"]
+        );
+        let params = ShowParams::default();
+        assert_eq!(
+            table.data(params, OutputFormat::Normal),
+            ["default", "nginx", "1/1"]
+        );
+    }
+
+    #[test]
+    fn show_kind() {
+        let mut table = table();
+        table.rows[0].object = json::from_value(json::json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {"name": "nginx", "namespace": "default"},
+        }))
+        .unwrap();
+        let params = ShowParams {
+            show_kind: true,
+            ..ShowParams::default()
+        };
+        assert_eq!(
+            table.data(params, OutputFormat::Normal),
+            ["default", "deployment.apps/nginx", "1/1"]
+        );
+    }
+
+    #[test]
+    fn wide_shows_all_columns() {
+        let table = table();
+        assert_eq!(
+            table.header(OutputFormat::Wide),
+            ["NAMESPACE", "NAME", "READY", "NODE"]
+        );
+        let params = ShowParams::default();
+        assert_eq!(
+            table.data(params, OutputFormat::Wide),
+            ["default", "nginx", "1/1", "<none>"]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use super::*;
+
+/// Type of change reported by a watch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchEventType {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl fmt::Display for WatchEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added => "ADDED".fmt(f),
+            Self::Modified => "MODIFIED".fmt(f),
+            Self::Deleted => "DELETED".fmt(f),
+        }
+    }
+}
+
+/// Object received from a watch, optionally shown along with the event type
+#[derive(Debug)]
+pub struct Watched<K> {
+    pub event: WatchEventType,
+    pub object: K,
+    pub show_event: bool,
+}
+
+impl<K> Show for Watched<K>
+where
+    K: Show,
+{
+    fn header(&self, output: OutputFormat) -> Vec<String> {
+        let event = self.show_event.then(|| "EVENT".to_string());
+        event
+            .into_iter()
+            .chain(self.object.header(output))
+            .collect()
+    }
+
+    fn data(&self, params: ShowParams, output: OutputFormat) -> Vec<String> {
+        let event = self.show_event.then(|| self.event.to_string());
+        event
+            .into_iter()
+            .chain(self.object.data(params, output))
+            .collect()
+    }
+
+    fn json(&self, params: ShowParams) -> String {
+        let object = self.object.json(params);
+        if self.show_event {
+            let object = json::from_str::<json::Value>(&object).unwrap_or_default();
+            let event = json::json!({ "type": self.event.to_string(), "object": object });
+            json::to_string_pretty(&event).unwrap_or_default()
+        } else {
+            object
+        }
+    }
+
+    fn yaml(&self, params: ShowParams) -> String {
+        let object = self.object.yaml(params);
+        if self.show_event {
+            let object = yaml::from_str::<yaml::Value>(&object).unwrap_or_default();
+            let event = BTreeMap::from([
+                ("type", yaml::Value::from(self.event.to_string())),
+                ("object", object),
+            ]);
+            yaml::to_string(&event).unwrap_or_default()
+        } else {
+            object
+        }
+    }
+
+    fn name(&self) -> String {
+        self.object.name()
+    }
+}
+
+/// Prints rows one batch at a time, reusing the column widths of the earlier batches.
+/// Columns only ever grow: a cell wider than its column widens it for the rows printed
+/// from then on, while the rows already printed, and the header, stay as they were.
+#[derive(Debug, Default)]
+pub struct RowPrinter {
+    widths: Vec<usize>,
+    header: bool,
+}
+
+impl RowPrinter {
+    const PADDING: usize = 2;
+
+    pub fn batch(
+        &mut self,
+        items: &[Box<dyn Show>],
+        namespace: bool,
+        params: ShowParams,
+        output: OutputFormat,
+    ) -> String {
+        match output {
+            OutputFormat::Normal | OutputFormat::Wide => {
+                self.table(items, namespace, params, output)
+            }
+            OutputFormat::Yaml => items
+                .iter()
+                .map(|item| format!("---\n{}", item.yaml(params).trim_end()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ => items
+                .iter()
+                .map(|item| item.output(namespace, params, output))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    fn table(
+        &mut self,
+        items: &[Box<dyn Show>],
+        namespace: bool,
+        params: ShowParams,
+        output: OutputFormat,
+    ) -> String {
+        let Some(first) = items.first() else {
+            return String::new();
+        };
+
+        let header = first.header(output);
+        let skip = header
+            .iter()
+            .position(|column| column == "NAMESPACE")
+            .filter(|_| !namespace);
+        let header = (!self.header).then_some(header);
+        self.header = true;
+
+        let rows = header
+            .into_iter()
+            .chain(items.iter().map(|item| item.data(params, output)))
+            .map(|mut row| {
+                if let Some(idx) = skip.filter(|idx| *idx < row.len()) {
+                    row.remove(idx);
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            if self.widths.len() < row.len() {
+                self.widths.resize(row.len(), 0);
+            }
+            for (width, cell) in self.widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        rows.iter()
+            .map(|row| self.row(row))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn row(&self, row: &[String]) -> String {
+        row.iter()
+            .zip(&self.widths)
+            .map(|(cell, width)| format!("{cell:<width$}", width = width + Self::PADDING))
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(pairs: &[(&str, &str)]) -> Vec<Box<dyn Show>> {
+        pairs
+            .iter()
+            .map(|(key, value)| Box::new((key.to_string(), value.to_string())) as Box<dyn Show>)
+            .collect()
+    }
+
+    #[test]
+    fn widths_are_stable_between_batches() {
+        let mut printer = RowPrinter::default();
+        let params = ShowParams::default();
+        let output = OutputFormat::Normal;
+
+        let first = printer.batch(&items(&[("nginx", "1")]), true, params, output);
+        assert_eq!(first, "Key    Value\nnginx  1");
+
+        let second = printer.batch(&items(&[("db", "2")]), true, params, output);
+        assert_eq!(second, "db     2");
+
+        // Wider than the header, the rows printed earlier can't follow
+        let third = printer.batch(&items(&[("frontend", "3")]), true, params, output);
+        assert_eq!(third, "frontend  3");
+    }
+}